@@ -0,0 +1,59 @@
+/* Generated by `cargo run -p aoc-ffi --bin header`. Do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* the answer was written to the buffer. */
+#define AOC_OK 0
+/* there is no solution for the day. */
+#define AOC_UNKNOWN_DAY 1
+/* the part is not 1 or 2. */
+#define AOC_INVALID_PART 2
+/* the input is null but its length is not zero. */
+#define AOC_NULL_POINTER 3
+/* the input is not valid UTF-8. */
+#define AOC_INVALID_UTF8 4
+/* the input does not match the format the day expects. */
+#define AOC_PARSE_ERROR 5
+/* the solver panicked. */
+#define AOC_PANICKED 6
+/* the answer does not fit in the buffer. */
+#define AOC_BUFFER_TOO_SMALL 7
+/* the answer overflows the widest type it can be solved in. */
+#define AOC_OVERFLOW 8
+/* the input has no answer for the part. */
+#define AOC_NO_ANSWER 9
+
+/*
+ * Solves one part of a day, writing the answer to out as a NUL-terminated string, or a message
+ * saying what went wrong in its place.
+ *
+ * *out_len is the size of out in bytes when called, and is set to the length of the whole answer
+ * or message, without the NUL. Anything that doesn't fit is cut short, and an answer that is cut
+ * short is reported as AOC_BUFFER_TOO_SMALL, so calling with a size of zero gives the size the
+ * buffer needs to be, less one.
+ *
+ * A day that panics is reported as AOC_PANICKED, after the Rust panic hook has printed the panic
+ * to stderr, as the hook belongs to the whole process rather than to one call.
+ *
+ * input may only be null if input_len is zero, and out may be null, which is taken as a size of
+ * zero.
+ */
+int32_t aoc_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t input_len, char *out,
+                  size_t *out_len);
+
+/* What a status means, as a static NUL-terminated string, or NULL if it isn't a status. */
+const char *aoc_status_message(int32_t status);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC_H */
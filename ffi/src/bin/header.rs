@@ -0,0 +1,5 @@
+//! Prints the C header for the library, which is kept in `include/aoc.h`.
+
+fn main() {
+    print!("{}", aoc_ffi::header());
+}
@@ -0,0 +1,420 @@
+//! A C interface to every day's solver, built as the `aoc_ffi` shared library so that programs in
+//! other languages can solve a day without running the binary. Its declarations are in
+//! `include/aoc.h`, which is generated from this crate with `cargo run -p aoc-ffi --bin header`.
+//!
+//! No panic crosses the boundary. Each call is run under [`panic::catch_unwind`], and anything
+//! that goes wrong is returned as a [`Status`] with a message written where the answer would be.
+//! A panic is still printed to stderr by the panic hook first, as the hook belongs to the whole
+//! process rather than to one call.
+
+use std::ffi::{c_char, CStr};
+use std::panic::{self, AssertUnwindSafe};
+use std::{ptr, slice, str};
+
+use aoc_2023::parallel::panic_message;
+use aoc_2023::registry::{self, Registration, SolveError};
+use aoc_2023::Part;
+
+/// The outcome of a call, which is returned to C as an `int32_t`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(i32)]
+pub enum Status {
+    Ok = 0,
+    UnknownDay = 1,
+    InvalidPart = 2,
+    NullPointer = 3,
+    InvalidUtf8 = 4,
+    ParseError = 5,
+    Panicked = 6,
+    BufferTooSmall = 7,
+    Overflow = 8,
+    NoAnswer = 9,
+}
+
+impl Status {
+    pub const ALL: [Status; 10] = [
+        Status::Ok,
+        Status::UnknownDay,
+        Status::InvalidPart,
+        Status::NullPointer,
+        Status::InvalidUtf8,
+        Status::ParseError,
+        Status::Panicked,
+        Status::BufferTooSmall,
+        Status::Overflow,
+        Status::NoAnswer,
+    ];
+
+    /// The name of the status's constant in the header.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "AOC_OK",
+            Status::UnknownDay => "AOC_UNKNOWN_DAY",
+            Status::InvalidPart => "AOC_INVALID_PART",
+            Status::NullPointer => "AOC_NULL_POINTER",
+            Status::InvalidUtf8 => "AOC_INVALID_UTF8",
+            Status::ParseError => "AOC_PARSE_ERROR",
+            Status::Panicked => "AOC_PANICKED",
+            Status::BufferTooSmall => "AOC_BUFFER_TOO_SMALL",
+            Status::Overflow => "AOC_OVERFLOW",
+            Status::NoAnswer => "AOC_NO_ANSWER",
+        }
+    }
+
+    /// What the status means, as returned by [`aoc_status_message`].
+    pub const fn message(self) -> &'static CStr {
+        match self {
+            Status::Ok => c"the answer was written to the buffer",
+            Status::UnknownDay => c"there is no solution for the day",
+            Status::InvalidPart => c"the part is not 1 or 2",
+            Status::NullPointer => c"the input is null but its length is not zero",
+            Status::InvalidUtf8 => c"the input is not valid UTF-8",
+            Status::ParseError => c"the input does not match the format the day expects",
+            Status::Panicked => c"the solver panicked",
+            Status::BufferTooSmall => c"the answer does not fit in the buffer",
+            Status::Overflow => c"the answer overflows the widest type it can be solved in",
+            Status::NoAnswer => c"the input has no answer for the part",
+        }
+    }
+}
+
+/// Solves one part of a day, writing the answer to `out` as a NUL-terminated string, or a
+/// message saying what went wrong in its place.
+///
+/// `*out_len` is the size of `out` in bytes when called, and is set to the length of the whole
+/// answer or message, without the NUL. Anything that doesn't fit is cut short, and an answer that
+/// is cut short is reported as [`Status::BufferTooSmall`], so calling with a size of zero gives
+/// the size the buffer needs to be, less one.
+///
+/// A day that panics is reported as [`Status::Panicked`], after the panic hook has printed the
+/// panic to stderr.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, and may only be null if `input_len` is zero.
+/// `out_len` must point to a `size_t`, and `out` to that many writable bytes, unless it is null,
+/// which is taken as a size of zero.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    out: *mut c_char,
+    out_len: *mut usize,
+) -> i32 {
+    let input = match (input.is_null(), input_len) {
+        (_, 0) => Some(&[][..]),
+        (true, _) => None,
+        (false, _) => Some(slice::from_raw_parts(input, input_len)),
+    };
+    let (status, text) = catch(|| solve(day, part, input));
+    write(status, &text, out, out_len) as i32
+}
+
+/// Runs a call, returning a panic in it as [`Status::Panicked`] with the panic's message.
+fn catch(call: impl FnOnce() -> Result<String, (Status, String)>) -> (Status, String) {
+    match panic::catch_unwind(AssertUnwindSafe(call)) {
+        Ok(Ok(answer)) => (Status::Ok, answer),
+        Ok(Err(failure)) => failure,
+        Err(payload) => (
+            Status::Panicked,
+            format!("panicked: {}", panic_message(&*payload)),
+        ),
+    }
+}
+
+/// What a status means, as a static NUL-terminated string, or null if it isn't a status.
+#[no_mangle]
+pub extern "C" fn aoc_status_message(status: i32) -> *const c_char {
+    Status::ALL
+        .into_iter()
+        .find(|known| *known as i32 == status)
+        .map_or(ptr::null(), |known| known.message().as_ptr())
+}
+
+fn solve(day: u8, part: u8, input: Option<&[u8]>) -> Result<String, (Status, String)> {
+    let registration = registry::find(day).ok_or_else(|| {
+        let message = format!("There is no solution for day {day}");
+        (Status::UnknownDay, message)
+    })?;
+    solve_with(registration, part, input)
+}
+
+fn solve_with(
+    registration: &Registration,
+    part: u8,
+    input: Option<&[u8]>,
+) -> Result<String, (Status, String)> {
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err((Status::InvalidPart, format!("Invalid part {part}"))),
+    };
+    let input = input.ok_or_else(|| {
+        let message = "The input is null but its length is not zero".to_string();
+        (Status::NullPointer, message)
+    })?;
+    let input = str::from_utf8(input).map_err(|error| {
+        let message = format!("The input is not valid UTF-8: {error}");
+        (Status::InvalidUtf8, message)
+    })?;
+    let mut solved = registration
+        .solve(input, &[part])
+        .map_err(|error| match error {
+            SolveError::Parse(error) => (Status::ParseError, error.to_string()),
+            SolveError::Overflow(overflow) => (Status::Overflow, overflow.to_string()),
+            SolveError::NoAnswer(no_answer) => (Status::NoAnswer, no_answer.to_string()),
+        })?;
+    Ok(solved.answers.remove(0).value)
+}
+
+/// Copies as much of `text` as fits into `out`, ending on a character boundary and followed by a
+/// NUL, and sets `*out_len` to the length of all of it.
+unsafe fn write(status: Status, text: &str, out: *mut c_char, out_len: *mut usize) -> Status {
+    if out_len.is_null() {
+        return Status::NullPointer;
+    }
+    let capacity = if out.is_null() { 0 } else { *out_len };
+    *out_len = text.len();
+    let Some(space) = capacity.checked_sub(1) else {
+        return cut_short(status);
+    };
+    let mut end = text.len().min(space);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    ptr::copy_nonoverlapping(text.as_ptr(), out.cast::<u8>(), end);
+    *out.add(end) = 0;
+    if end < text.len() {
+        cut_short(status)
+    } else {
+        status
+    }
+}
+
+/// A status for text that didn't fit, which only changes if the text was the answer.
+fn cut_short(status: Status) -> Status {
+    match status {
+        Status::Ok => Status::BufferTooSmall,
+        _ => status,
+    }
+}
+
+/// The C header declaring the library's functions and statuses.
+pub fn header() -> String {
+    let mut statuses = String::new();
+    for status in Status::ALL {
+        let message = status.message().to_string_lossy();
+        statuses += &format!("/* {message}. */\n");
+        statuses += &format!("#define {} {}\n", status.name(), status as i32);
+    }
+    format!(
+        "\
+/* Generated by `cargo run -p aoc-ffi --bin header`. Do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+{statuses}
+/*
+ * Solves one part of a day, writing the answer to out as a NUL-terminated string, or a message
+ * saying what went wrong in its place.
+ *
+ * *out_len is the size of out in bytes when called, and is set to the length of the whole answer
+ * or message, without the NUL. Anything that doesn't fit is cut short, and an answer that is cut
+ * short is reported as AOC_BUFFER_TOO_SMALL, so calling with a size of zero gives the size the
+ * buffer needs to be, less one.
+ *
+ * A day that panics is reported as AOC_PANICKED, after the Rust panic hook has printed the panic
+ * to stderr, as the hook belongs to the whole process rather than to one call.
+ *
+ * input may only be null if input_len is zero, and out may be null, which is taken as a size of
+ * zero.
+ */
+int32_t aoc_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t input_len, char *out,
+                  size_t *out_len);
+
+/* What a status means, as a static NUL-terminated string, or NULL if it isn't a status. */
+const char *aoc_status_message(int32_t status);
+
+#ifdef __cplusplus
+}}
+#endif
+
+#endif /* AOC_H */
+"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2023::{ParseError, Solution};
+
+    /// A day that panics while solving, for the panic path that no real day takes.
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u8 = 26;
+        const TITLE: &'static str = "Panics";
+        type Parsed<'a> = ();
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(_input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            Ok(())
+        }
+
+        fn solve_part_1(_parsed: &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+            panic!("deliberately")
+        }
+
+        fn solve_part_2(_parsed: &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+            panic!("deliberately")
+        }
+    }
+
+    /// Calls [`aoc_solve`] with a buffer of `capacity` bytes, returning the status, the text in
+    /// the buffer and the length it was given.
+    fn call(day: u8, part: u8, input: &[u8], capacity: usize) -> (Status, String, usize) {
+        let mut out = vec![1 as c_char; capacity.max(1)];
+        let mut out_len = capacity;
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                &mut out_len,
+            )
+        };
+        let status = Status::ALL
+            .into_iter()
+            .find(|known| *known as i32 == status)
+            .unwrap();
+        let text = match capacity {
+            0 => String::new(),
+            _ => unsafe { CStr::from_ptr(out.as_ptr()) }
+                .to_str()
+                .unwrap()
+                .to_string(),
+        };
+        (status, text, out_len)
+    }
+
+    #[test]
+    fn test_solve() {
+        const INPUT: &[u8] = b"Time:      7  15   30\nDistance:  9  40  200\n";
+        assert_eq!(call(6, 1, INPUT, 64), (Status::Ok, "288".to_string(), 3));
+        assert_eq!(call(6, 2, INPUT, 6), (Status::Ok, "71503".to_string(), 5));
+    }
+
+    #[test]
+    fn test_solve_buffer_too_small() {
+        const INPUT: &[u8] = b"Time:      7  15   30\nDistance:  9  40  200\n";
+        assert_eq!(
+            call(6, 2, INPUT, 5),
+            (Status::BufferTooSmall, "7150".to_string(), 5)
+        );
+        assert_eq!(
+            call(6, 2, INPUT, 0),
+            (Status::BufferTooSmall, String::new(), 5)
+        );
+        let output = call(26, 1, b"", 11);
+        assert_eq!(output.0, Status::UnknownDay);
+        assert_eq!(output.1, "There is n");
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        // the difference between the numbers doesn't fit in day 9's i128 either.
+        const OVERFLOW: &[u8] =
+            b"170141183460469231731687303715884105727 -170141183460469231731687303715884105728";
+        const EXPECTED: &[(Status, &str)] = &[
+            (Status::UnknownDay, "There is no solution for day 26"),
+            (Status::InvalidPart, "Invalid part 3"),
+            (
+                Status::InvalidUtf8,
+                "The input is not valid UTF-8: invalid utf-8 sequence of 1 bytes from index 6",
+            ),
+            (
+                Status::ParseError,
+                "day 6, line 1, column 7: expected a number, found \"x\"",
+            ),
+            (
+                Status::Overflow,
+                "day 9, part 1: -170141183460469231731687303715884105728 - \
+                170141183460469231731687303715884105727 overflows i128",
+            ),
+            (
+                Status::ParseError,
+                "day 8, line 4, column 1: expected a node labelled AAA to start from, found end of \
+                line",
+            ),
+            (
+                Status::NoAnswer,
+                "day 8, part 1: the walk from AAA goes round in circles from AAA without reaching \
+                the end",
+            ),
+        ];
+        let output = [
+            call(26, 1, b"", 64),
+            call(6, 3, b"", 64),
+            call(6, 1, b"Time: \xff", 128),
+            call(6, 1, b"Time: x\nDistance: 9\n", 128),
+            call(9, 1, OVERFLOW, 256),
+            call(8, 1, b"L\n\nBBB = (BBB, BBB)\n", 256),
+            call(8, 1, b"L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n", 256),
+        ];
+        let output: Vec<_> = output
+            .iter()
+            .map(|(status, text, _)| (*status, text.as_str()))
+            .collect();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_solve_null_pointers() {
+        let mut out_len = 0;
+        let status = unsafe { aoc_solve(6, 1, ptr::null(), 4, ptr::null_mut(), &mut out_len) };
+        assert_eq!(status, Status::NullPointer as i32);
+        assert_eq!(out_len, 44);
+        let status = unsafe { aoc_solve(6, 1, ptr::null(), 0, ptr::null_mut(), ptr::null_mut()) };
+        assert_eq!(status, Status::NullPointer as i32);
+    }
+
+    #[test]
+    fn test_solve_panic() {
+        let registration = Registration::new::<Panics>();
+        let output = catch(|| solve_with(&registration, 1, Some(b"")));
+        assert_eq!(
+            output,
+            (Status::Panicked, "panicked: deliberately".to_string())
+        );
+    }
+
+    #[test]
+    fn test_status_message() {
+        let message = unsafe { CStr::from_ptr(aoc_status_message(7)) };
+        assert_eq!(message, c"the answer does not fit in the buffer");
+        assert!(aoc_status_message(10).is_null());
+    }
+
+    #[test]
+    fn test_header_is_up_to_date() {
+        assert_eq!(
+            include_str!("../include/aoc.h"),
+            header(),
+            "run `cargo run -p aoc-ffi --bin header > ffi/include/aoc.h`"
+        );
+    }
+}
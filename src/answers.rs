@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::{ParseError, Part};
+
+/// Path of the known answers manifest, relative to the root of the repository.
+pub const MANIFEST_PATH: &str = "data/answers.toml";
+
+/// Known answers for the real inputs, read from a manifest of the form:
+///
+/// ```toml
+/// [day01]
+/// part1 = 54331
+/// part2 = "54518"
+///
+/// [day01.alice]
+/// part1 = 55108
+/// ```
+///
+/// where a `[dayNN.name]` table holds the answers for the named input `dayNN/name.txt`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Option<String>, Part), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+        Self::parse(&text).map_err(|error| format!("{}: {error}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut answers = BTreeMap::new();
+        let mut current = None;
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(table) = line.strip_prefix('[') {
+                let name = table.strip_suffix(']').ok_or_else(|| {
+                    ParseError::at_end(line, "\"]\" after the table name").within(text, line)
+                })?;
+                current = Some(parse_table(text, name)?);
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::new(text, line, "a part = answer pair"))?;
+            let (day, name) = current
+                .clone()
+                .ok_or_else(|| ParseError::new(text, line, "a [dayNN] table"))?;
+            let part = parse_part(text, key.trim())?;
+            let value = parse_value(text, value.trim())?;
+            answers.insert((day, name, part), value.to_string());
+        }
+        Ok(Self { answers })
+    }
+
+    /// The known answer for a part of a day, if one has been recorded.
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, None, part)).map(String::as_str)
+    }
+
+    /// The known answer for a part of one of a day's named inputs, if one has been recorded.
+    pub fn get_named(&self, day: u8, name: &str, part: Part) -> Option<&str> {
+        self.answers
+            .get(&(day, Some(name.to_string()), part))
+            .map(String::as_str)
+    }
+}
+
+fn parse_table(text: &str, table: &str) -> Result<(u8, Option<String>), ParseError> {
+    let (day, name) = match table.split_once('.') {
+        Some((day, name)) if !name.is_empty() => (day, Some(name.to_string())),
+        Some(_) => return Err(ParseError::at_end(table, "an input name").within(text, table)),
+        None => (table, None),
+    };
+    let day = day
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .ok_or_else(|| ParseError::new(text, day, "a table named dayNN"))?;
+    Ok((day, name))
+}
+
+fn parse_part(text: &str, key: &str) -> Result<Part, ParseError> {
+    match key {
+        "part1" => Ok(Part::One),
+        "part2" => Ok(Part::Two),
+        _ => Err(ParseError::new(text, key, "part1 or part2")),
+    }
+}
+
+fn parse_value<'a>(text: &str, value: &'a str) -> Result<&'a str, ParseError> {
+    if let Some(string) = value.strip_prefix('"') {
+        string
+            .strip_suffix('"')
+            .ok_or_else(|| ParseError::new(text, value, "a quoted answer"))
+    } else if !value.is_empty()
+        && value
+            .trim_start_matches('-')
+            .chars()
+            .all(|c| c.is_ascii_digit())
+    {
+        Ok(value)
+    } else {
+        Err(ParseError::new(text, value, "an integer or quoted answer"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        const INPUT: &str = "
+        # answers for the real inputs
+        [day01]
+        part1 = 54331
+        part2 = \"54518\"
+
+        [day09]
+        part2 = -1136
+        ";
+        let output = Answers::parse(INPUT).unwrap();
+        assert_eq!(output.get(1, Part::One), Some("54331"));
+        assert_eq!(output.get(1, Part::Two), Some("54518"));
+        assert_eq!(output.get(9, Part::One), None);
+        assert_eq!(output.get(9, Part::Two), Some("-1136"));
+    }
+
+    #[test]
+    fn test_parse_named_inputs() {
+        const INPUT: &str = "
+        [day08]
+        part1 = 11911
+
+        [day08.alice]
+        part1 = 20093
+        part2 = 22103062509257
+        ";
+        let output = Answers::parse(INPUT).unwrap();
+        assert_eq!(output.get(8, Part::One), Some("11911"));
+        assert_eq!(output.get(8, Part::Two), None);
+        assert_eq!(output.get_named(8, "alice", Part::One), Some("20093"));
+        assert_eq!(
+            output.get_named(8, "alice", Part::Two),
+            Some("22103062509257")
+        );
+        assert_eq!(output.get_named(8, "bob", Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_table() {
+        const INPUT: &str = "
+        [dya08.alice]
+        part1 = 20093
+        ";
+        const EXPECTED: &str = "line 2, column 10: expected a table named dayNN, found \"dya08\"";
+        let output = Answers::parse(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_invalid_part() {
+        const INPUT: &str = "
+        [day01]
+        part3 = 54331
+        ";
+        const EXPECTED: &str = "line 3, column 9: expected part1 or part2, found \"part3\"";
+        let output = Answers::parse(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_missing_table() {
+        const INPUT: &str = "part1 = 54331";
+        const EXPECTED: &str =
+            "line 1, column 1: expected a [dayNN] table, found \"part1 = 54331\"";
+        let output = Answers::parse(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+}
@@ -0,0 +1,351 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::json::Value;
+use crate::registry::{Registration, SolveError};
+use crate::{ParseError, Part};
+
+/// Where baselines are stored unless another path is given, relative to the root of the
+/// repository.
+pub const DEFAULT_BASELINE_PATH: &str = "target/bench/baseline.json";
+
+/// Each day is repeated until it has run for at least this long...
+const TARGET_TIME: Duration = Duration::from_secs(1);
+/// ...and at least this many times...
+const MIN_SAMPLES: usize = 5;
+/// ...but never more than this many times.
+const MAX_SAMPLES: usize = 1000;
+
+/// A part of solving a day that is timed separately.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [
+        Phase::Parse,
+        Phase::Solve(Part::One),
+        Phase::Solve(Part::Two),
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Solve(Part::One) => "part1",
+            Phase::Solve(Part::Two) => "part2",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|phase| phase.key() == key)
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "Parse"),
+            Phase::Solve(part) => write!(f, "Part {part}"),
+        }
+    }
+}
+
+/// Summary of the samples taken for a phase, with the spread given as the median absolute
+/// deviation so that a few slow outliers don't swamp it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub median: Duration,
+    pub spread: Duration,
+    pub samples: usize,
+}
+
+impl Stats {
+    /// Summarises samples, returning `None` if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let middle = median(samples)?;
+        let deviations: Vec<_> = samples
+            .iter()
+            .map(|sample| sample.abs_diff(middle))
+            .collect();
+        Some(Self {
+            median: middle,
+            spread: median(&deviations)?,
+            samples: samples.len(),
+        })
+    }
+
+    /// The percentage change in the median from `baseline` to these stats, or `None` if the
+    /// baseline's median is zero, as there is no percentage of nothing.
+    pub fn change_from(&self, baseline: &Stats) -> Option<f64> {
+        let baseline = baseline.median.as_secs_f64();
+        if baseline == 0.0 {
+            return None;
+        }
+        Some((self.median.as_secs_f64() - baseline) / baseline * 100.0)
+    }
+
+    fn to_json(self) -> Value {
+        Value::from([
+            ("median_ns", Value::from(self.median.as_nanos() as f64)),
+            ("spread_ns", Value::from(self.spread.as_nanos() as f64)),
+            ("samples", Value::from(self.samples as f64)),
+        ])
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let nanos = |key| {
+            value
+                .get(key)?
+                .as_f64()
+                .map(|n| Duration::from_nanos(n as u64))
+        };
+        Some(Self {
+            median: nanos("median_ns")?,
+            spread: nanos("spread_ns")?,
+            samples: value.get("samples")?.as_f64()? as usize,
+        })
+    }
+}
+
+/// The timings of every phase of a day.
+#[derive(Debug)]
+pub struct Measurement {
+    pub day: u8,
+    pub phases: BTreeMap<Phase, Stats>,
+}
+
+/// Solves a day repeatedly on `input`, timing parsing and each part separately.
+pub fn measure(registration: &Registration, input: &str) -> Result<Measurement, SolveError> {
+    measure_for(registration, input, TARGET_TIME)
+}
+
+fn measure_for(
+    registration: &Registration,
+    input: &str,
+    target: Duration,
+) -> Result<Measurement, SolveError> {
+    let mut samples: BTreeMap<Phase, Vec<Duration>> = BTreeMap::new();
+    let start = Instant::now();
+    for count in 1..=MAX_SAMPLES {
+        let solved = registration.solve(input, &Part::ALL)?;
+        samples
+            .entry(Phase::Parse)
+            .or_default()
+            .push(solved.parse_time);
+        for answer in solved.answers {
+            samples
+                .entry(Phase::Solve(answer.part))
+                .or_default()
+                .push(answer.time);
+        }
+        if count >= MIN_SAMPLES && start.elapsed() >= target {
+            break;
+        }
+    }
+    let phases = samples
+        .into_iter()
+        .filter_map(|(phase, samples)| Some((phase, Stats::from_samples(&samples)?)))
+        .collect();
+    Ok(Measurement {
+        day: registration.day(),
+        phases,
+    })
+}
+
+/// Previously recorded timings to compare new measurements against.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    days: BTreeMap<u8, BTreeMap<Phase, Stats>>,
+}
+
+impl Baseline {
+    /// Loads a baseline, starting an empty one if none has been saved yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Self::default())
+            }
+            Err(error) => return Err(format!("Failed to read {}: {error}", path.display())),
+        };
+        Self::parse(&text).map_err(|error| format!("{}: {error}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let value = Value::parse(text)?;
+        let invalid =
+            || ParseError::new(text, text.trim_start(), "a baseline written by aoc bench");
+        let mut days = BTreeMap::new();
+        for (day, phases) in value
+            .get("days")
+            .and_then(Value::as_object)
+            .ok_or_else(invalid)?
+        {
+            let day = day.parse().map_err(|_| invalid())?;
+            let phases = phases
+                .as_object()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(|(phase, stats)| Some((Phase::from_key(phase)?, Stats::from_json(stats)?)))
+                .collect::<Option<_>>()
+                .ok_or_else(invalid)?;
+            days.insert(day, phases);
+        }
+        Ok(Self { days })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let write = || {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, format!("{self}\n"))
+        };
+        write().map_err(|error| format!("Failed to write {}: {error}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<&Stats> {
+        self.days.get(&day)?.get(&phase)
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.days.contains_key(&day)
+    }
+
+    /// Records a measurement, replacing any previous timings for its day.
+    pub fn record(&mut self, measurement: &Measurement) {
+        self.days
+            .insert(measurement.day, measurement.phases.clone());
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let days = self
+            .days
+            .iter()
+            .map(|(day, phases)| {
+                let phases = phases
+                    .iter()
+                    .map(|(phase, stats)| (phase.key().to_string(), stats.to_json()))
+                    .collect();
+                (day.to_string(), Value::Object(phases))
+            })
+            .collect();
+        write!(f, "{}", Value::from([("days", Value::Object(days))]))
+    }
+}
+
+fn median(samples: &[Duration]) -> Option<Duration> {
+    let mut samples = samples.to_vec();
+    samples.sort();
+    let middle = samples.len() / 2;
+    match samples.len() {
+        0 => None,
+        length if length % 2 == 0 => Some((samples[middle - 1] + samples[middle]) / 2),
+        _ => Some(samples[middle]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let input = micros(&[12, 10, 11, 50, 10]);
+        const EXPECTED: Stats = Stats {
+            median: Duration::from_micros(11),
+            spread: Duration::from_micros(1),
+            samples: 5,
+        };
+        let output = Stats::from_samples(&input);
+        assert_eq!(output, Some(EXPECTED));
+    }
+
+    #[test]
+    fn test_stats_from_samples_even() {
+        let input = micros(&[10, 20, 40, 30]);
+        const EXPECTED: Duration = Duration::from_micros(25);
+        let output = Stats::from_samples(&input).map(|stats| stats.median);
+        assert_eq!(output, Some(EXPECTED));
+    }
+
+    #[test]
+    fn test_stats_from_no_samples() {
+        let output = Stats::from_samples(&[]);
+        assert_eq!(output, None);
+    }
+
+    #[test]
+    fn test_change_from() {
+        let baseline = Stats::from_samples(&micros(&[200])).unwrap();
+        let input = Stats::from_samples(&micros(&[150])).unwrap();
+        const EXPECTED: f64 = -25.0;
+        let output = input.change_from(&baseline).unwrap();
+        assert!((output - EXPECTED).abs() < 1e-9);
+        let baseline = Stats::from_samples(&micros(&[0])).unwrap();
+        assert_eq!(input.change_from(&baseline), None);
+    }
+
+    #[test]
+    fn test_measure() {
+        const INPUT: &str = "
+        0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45
+        ";
+        let output = measure_for(registry::find(9).unwrap(), INPUT, Duration::ZERO).unwrap();
+        assert_eq!(output.day, 9);
+        assert_eq!(
+            output.phases.keys().copied().collect::<Vec<_>>(),
+            Phase::ALL
+        );
+        assert!(output
+            .phases
+            .values()
+            .all(|stats| stats.samples == MIN_SAMPLES));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let phases = Phase::ALL
+            .into_iter()
+            .zip([3, 50, 700])
+            .map(|(phase, micros)| {
+                (
+                    phase,
+                    Stats::from_samples(&[Duration::from_micros(micros)]).unwrap(),
+                )
+            })
+            .collect();
+        let mut input = Baseline::default();
+        input.record(&Measurement { day: 3, phases });
+        let output = Baseline::parse(&input.to_string()).unwrap();
+        assert_eq!(output, input);
+        assert_eq!(
+            output
+                .get(3, Phase::Solve(Part::Two))
+                .map(|stats| stats.median),
+            Some(Duration::from_micros(700))
+        );
+    }
+
+    #[test]
+    fn test_baseline_parse_invalid() {
+        const INPUT: &str = "{\"days\": {\"3\": {\"part3\": {}}}}";
+        const EXPECTED: &str = "line 1, column 1: expected a baseline written by aoc bench, found \"{\\\"days\\\": {\\\"3\\\": {\\\"part3\\\": {}}}}\"";
+        let output = Baseline::parse(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+}
@@ -0,0 +1,797 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc_2023::bench::DEFAULT_BASELINE_PATH;
+use aoc_2023::input::{InputSource, INPUT_DIR_VARIABLE};
+use aoc_2023::registry::{self, Registration};
+use aoc_2023::{trace, Part};
+
+use crate::output::Format;
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run <day|all> [--part <1|2>] [--input <path|->] [--format <text|json|csv>] [--stream]
+            [--threads <n>] [--checked] [--trace <text|json>]
+    aoc check <day|all>
+    aoc list
+    aoc bench <day|all> [--input <path|->] [--baseline <path>] [--save] [--threads <n>]
+    aoc fetch <day>
+    aoc submit <day> <1|2> [answer]
+    aoc new <day>
+    aoc readme [--times]
+    aoc generate <day> [--seed <n>] [--size <n>]
+    aoc diff <day|all> [--seeds <n>] [--size <n>]
+    aoc shrink <day> [--input <path|->]
+    aoc watch <day> [--interval <ms>]
+    aoc render <day> [--input <path|->] [--format <ansi|svg>]
+
+Inputs are read from data/dayNN.txt unless --input is given, where - reads from stdin.
+The directory can be changed with the AOC_INPUT_DIR environment variable.
+
+run all solves the days at the same time on a thread for each core and prints a table of their
+answers, parse and solve times, and the error for any day that couldn't be solved.
+
+With --stream, run reads the input a line at a time instead of loading all of it, for the days
+that can be solved that way, and solves both parts in the same pass.
+
+With --threads, run and bench split the work within a day that is independent for each line or
+item between that many threads, which gives the same answers as a single thread.
+
+Answers that overflow the integer type a day solves in are solved again in a wider one. With
+--checked, run reports the operation that overflowed as an error instead, as it does for an
+answer that overflows the wider type too.
+
+With --trace, run prints the events each day emits while solving to stderr, such as why a game
+was impossible or which hand type a joker upgrade chose, as indented text or as JSON lines.
+
+With --format json or csv, run prints a record for every answer with its type and how long it
+took, and a record with the error for every day that couldn't be solved.
+
+check solves data/dayNN.txt and every input in data/dayNN/, such as one for each account, and
+compares the answers to the ones in data/answers.toml, where [dayNN.name] holds the answers for
+data/dayNN/name.txt. Any input whose answers don't match is flagged.
+
+Benchmarks are compared against the baseline in target/bench/baseline.json, which is
+recorded the first time a day is benchmarked and replaced when --save is given.
+
+fetch downloads an input into the input directory unless it is already there. It needs the
+session token from the site's cookie in AOC_SESSION or in aoc.toml as session = \"...\",
+where base_url = \"...\" or AOC_BASE_URL can point it somewhere else.
+
+submit solves the part unless an answer is given, and posts it with the same settings. Every
+attempt is recorded in data/submissions.json, and answers already known to be wrong are
+refused.
+
+new creates src/days/dayNN.rs from templates/day.rs.template, registers it and creates an
+empty data/dayNN.txt. It must be run from the root of the repository.
+
+readme rebuilds the progress table in README.md, marking a part as done when it gives its known
+answer from data/answers.toml. --times adds the median runtime of each day.
+
+generate prints a random input for a day, which is always the same for the same seed. The size
+is roughly the number of lines, and defaults to 100. Pipe it into run or bench with --input -.
+
+diff runs every implementation of the days that have more than one on the inputs generated from
+seeds 0 to n - 1, 100 by default. The first input they disagree on, or that one of them panics
+on, is shrunk to a small input that still fails the same way and written to target/shrink.
+
+shrink does the same for an input that a day panics on, or that its implementations disagree
+on.
+
+watch solves data/dayNN.txt and the inputs in data/dayNN/, such as the puzzle's examples, every
+time one of them changes, checking every 500ms by default. Answers that differ from the last time
+are shown with the answer they were before.
+
+render draws the input of a grid puzzle with its cells coloured by what they mean to the answer,
+with ANSI colours for the terminal or as an SVG image.";
+
+/// The size of a generated input when --size isn't given.
+const DEFAULT_GENERATE_SIZE: usize = 100;
+
+/// How many generated inputs diff compares on when --seeds isn't given.
+const DEFAULT_DIFF_SEEDS: u64 = 100;
+
+/// How often watch checks for changes when --interval isn't given, in milliseconds.
+const DEFAULT_WATCH_INTERVAL: u64 = 500;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        selection: Selection,
+        part: Option<Part>,
+        input: InputSource,
+        format: Format,
+        stream: bool,
+        threads: usize,
+        checked: bool,
+        trace: Option<trace::Format>,
+    },
+    Check {
+        selection: Selection,
+    },
+    List,
+    Bench {
+        selection: Selection,
+        input: InputSource,
+        baseline: PathBuf,
+        save: bool,
+        threads: usize,
+    },
+    Fetch {
+        day: u8,
+    },
+    Submit {
+        day: u8,
+        part: Part,
+        answer: Option<String>,
+    },
+    New {
+        day: u8,
+    },
+    Readme {
+        times: bool,
+    },
+    Generate {
+        day: u8,
+        seed: u64,
+        size: usize,
+    },
+    Diff {
+        selection: Selection,
+        seeds: u64,
+        size: Option<usize>,
+    },
+    Shrink {
+        day: u8,
+        input: InputSource,
+    },
+    Watch {
+        day: u8,
+        interval: Duration,
+    },
+    Render {
+        day: u8,
+        input: InputSource,
+        format: RenderFormat,
+    },
+}
+
+/// What render draws the grid as.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RenderFormat {
+    Ansi,
+    Svg,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+impl Selection {
+    pub fn registrations(&self) -> Result<Vec<&'static Registration>, String> {
+        match self {
+            Selection::All => Ok(registry::SOLUTIONS.iter().collect()),
+            Selection::Day(day) => registry::find(*day)
+                .map(|registration| vec![registration])
+                .ok_or_else(|| format!("Day {day} has no registered solution")),
+        }
+    }
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let command = args.next().ok_or("Missing command")?;
+    match command.as_str() {
+        "run" => {
+            let selection = parse_selection(args.next())?;
+            let mut part = None;
+            let mut input = InputSource::Default;
+            let mut format = Format::Text;
+            let mut stream = false;
+            let mut threads = 1;
+            let mut checked = false;
+            let mut trace = None;
+            while let Some(flag) = args.next() {
+                match flag.as_str() {
+                    "--part" => part = Some(parse_part(args.next())?),
+                    "--input" => input = parse_input(args.next(), &selection)?,
+                    "--format" => format = args.next().ok_or("Missing format")?.parse()?,
+                    "--stream" => stream = true,
+                    "--threads" => threads = parse_number(args.next(), "number of threads")?,
+                    "--checked" => checked = true,
+                    "--trace" => trace = Some(args.next().ok_or("Missing trace format")?.parse()?),
+                    _ => return Err(format!("Unexpected argument {flag}")),
+                }
+            }
+            Ok(Command::Run {
+                selection,
+                part,
+                input,
+                format,
+                stream,
+                threads,
+                checked,
+                trace,
+            })
+        }
+        "check" => {
+            let selection = parse_selection(args.next())?;
+            expect_end(args)?;
+            Ok(Command::Check { selection })
+        }
+        "list" => {
+            expect_end(args)?;
+            Ok(Command::List)
+        }
+        "bench" => {
+            let selection = parse_selection(args.next())?;
+            let mut input = InputSource::Default;
+            let mut baseline = PathBuf::from(DEFAULT_BASELINE_PATH);
+            let mut save = false;
+            let mut threads = 1;
+            while let Some(flag) = args.next() {
+                match flag.as_str() {
+                    "--input" => input = parse_input(args.next(), &selection)?,
+                    "--baseline" => {
+                        baseline = args
+                            .next()
+                            .map(PathBuf::from)
+                            .ok_or("Missing baseline path")?
+                    }
+                    "--save" => save = true,
+                    "--threads" => threads = parse_number(args.next(), "number of threads")?,
+                    _ => return Err(format!("Unexpected argument {flag}")),
+                }
+            }
+            Ok(Command::Bench {
+                selection,
+                input,
+                baseline,
+                save,
+                threads,
+            })
+        }
+        "fetch" => {
+            let day = parse_day(args.next())?;
+            expect_end(args)?;
+            Ok(Command::Fetch { day })
+        }
+        "submit" => {
+            let day = parse_day(args.next())?;
+            let part = parse_part(args.next())?;
+            let answer = args.next();
+            expect_end(args)?;
+            Ok(Command::Submit { day, part, answer })
+        }
+        "new" => {
+            let day = parse_day(args.next())?;
+            expect_end(args)?;
+            Ok(Command::New { day })
+        }
+        "readme" => {
+            let times = match args.next().as_deref() {
+                Some("--times") => true,
+                Some(arg) => return Err(format!("Unexpected argument {arg}")),
+                None => false,
+            };
+            expect_end(args)?;
+            Ok(Command::Readme { times })
+        }
+        "generate" => {
+            let day = parse_day(args.next())?;
+            let mut seed = 0;
+            let mut size = DEFAULT_GENERATE_SIZE;
+            while let Some(flag) = args.next() {
+                match flag.as_str() {
+                    "--seed" => seed = parse_number(args.next(), "seed")?,
+                    "--size" => size = parse_number(args.next(), "size")?,
+                    _ => return Err(format!("Unexpected argument {flag}")),
+                }
+            }
+            Ok(Command::Generate { day, seed, size })
+        }
+        "diff" => {
+            let selection = parse_selection(args.next())?;
+            let mut seeds = DEFAULT_DIFF_SEEDS;
+            let mut size = None;
+            while let Some(flag) = args.next() {
+                match flag.as_str() {
+                    "--seeds" => seeds = parse_number(args.next(), "number of seeds")?,
+                    "--size" => size = Some(parse_number(args.next(), "size")?),
+                    _ => return Err(format!("Unexpected argument {flag}")),
+                }
+            }
+            Ok(Command::Diff {
+                selection,
+                seeds,
+                size,
+            })
+        }
+        "shrink" => {
+            let day = parse_day(args.next())?;
+            let input = match args.next().as_deref() {
+                Some("--input") => parse_input(args.next(), &Selection::Day(day))?,
+                Some(arg) => return Err(format!("Unexpected argument {arg}")),
+                None => InputSource::Default,
+            };
+            expect_end(args)?;
+            Ok(Command::Shrink { day, input })
+        }
+        "watch" => {
+            let day = parse_day(args.next())?;
+            let interval = match args.next().as_deref() {
+                Some("--interval") => parse_number(args.next(), "interval")?,
+                Some(arg) => return Err(format!("Unexpected argument {arg}")),
+                None => DEFAULT_WATCH_INTERVAL,
+            };
+            expect_end(args)?;
+            Ok(Command::Watch {
+                day,
+                interval: Duration::from_millis(interval),
+            })
+        }
+        "render" => {
+            let day = parse_day(args.next())?;
+            let mut input = InputSource::Default;
+            let mut format = RenderFormat::Ansi;
+            while let Some(flag) = args.next() {
+                match flag.as_str() {
+                    "--input" => input = parse_input(args.next(), &Selection::Day(day))?,
+                    "--format" => {
+                        format = match args.next().as_deref() {
+                            Some("ansi") => RenderFormat::Ansi,
+                            Some("svg") => RenderFormat::Svg,
+                            Some(arg) => return Err(format!("Invalid format {arg}")),
+                            None => return Err("Missing format".to_string()),
+                        }
+                    }
+                    _ => return Err(format!("Unexpected argument {flag}")),
+                }
+            }
+            Ok(Command::Render { day, input, format })
+        }
+        _ => Err(format!("Unknown command {command}")),
+    }
+}
+
+fn parse_selection(arg: Option<String>) -> Result<Selection, String> {
+    let arg = arg.ok_or("Missing day")?;
+    if arg == "all" {
+        Ok(Selection::All)
+    } else {
+        arg.parse()
+            .map(Selection::Day)
+            .map_err(|_| format!("Invalid day {arg}"))
+    }
+}
+
+fn parse_day(arg: Option<String>) -> Result<u8, String> {
+    let arg = arg.ok_or("Missing day")?;
+    match arg.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("Invalid day {arg}")),
+    }
+}
+
+fn parse_part(arg: Option<String>) -> Result<Part, String> {
+    match arg.as_deref() {
+        Some("1") => Ok(Part::One),
+        Some("2") => Ok(Part::Two),
+        Some(arg) => Err(format!("Invalid part {arg}")),
+        None => Err("Missing part".to_string()),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(arg: Option<String>, name: &str) -> Result<T, String> {
+    let arg = arg.ok_or_else(|| format!("Missing {name}"))?;
+    arg.parse().map_err(|_| format!("Invalid {name} {arg}"))
+}
+
+fn parse_input(arg: Option<String>, selection: &Selection) -> Result<InputSource, String> {
+    if *selection == Selection::All {
+        return Err(format!(
+            "--input can only be used with a single day, use {INPUT_DIR_VARIABLE} instead"
+        ));
+    }
+    match arg.as_deref() {
+        Some("-") => Ok(InputSource::Stdin),
+        Some(path) => Ok(InputSource::Path(PathBuf::from(path))),
+        None => Err("Missing input path".to_string()),
+    }
+}
+
+fn expect_end(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    match args.next() {
+        Some(arg) => Err(format!("Unexpected argument {arg}")),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(string: &str) -> Vec<String> {
+        string.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args_run_day() {
+        const INPUT: &str = "run 5";
+        const EXPECTED: Command = Command::Run {
+            selection: Selection::Day(5),
+            part: None,
+            input: InputSource::Default,
+            format: Format::Text,
+            stream: false,
+            threads: 1,
+            checked: false,
+            trace: None,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_run_day_with_part() {
+        const INPUT: &str = "run 5 --part 2";
+        const EXPECTED: Command = Command::Run {
+            selection: Selection::Day(5),
+            part: Some(Part::Two),
+            input: InputSource::Default,
+            format: Format::Text,
+            stream: false,
+            threads: 1,
+            checked: false,
+            trace: None,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_run_all() {
+        const INPUT: &str = "run all";
+        const EXPECTED: Command = Command::Run {
+            selection: Selection::All,
+            part: None,
+            input: InputSource::Default,
+            format: Format::Text,
+            stream: false,
+            threads: 1,
+            checked: false,
+            trace: None,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_run_streaming() {
+        const INPUT: &str = "run 9 --input - --stream";
+        const EXPECTED: Command = Command::Run {
+            selection: Selection::Day(9),
+            part: None,
+            input: InputSource::Stdin,
+            format: Format::Text,
+            stream: true,
+            threads: 1,
+            checked: false,
+            trace: None,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_run_with_threads() {
+        const INPUT: &str = "run all --threads 8";
+        const EXPECTED: Command = Command::Run {
+            selection: Selection::All,
+            part: None,
+            input: InputSource::Default,
+            format: Format::Text,
+            stream: false,
+            threads: 8,
+            checked: false,
+            trace: None,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_run_checked() {
+        const INPUT: &str = "run 1 --checked";
+        const EXPECTED: Command = Command::Run {
+            selection: Selection::Day(1),
+            part: None,
+            input: InputSource::Default,
+            format: Format::Text,
+            stream: false,
+            threads: 1,
+            checked: true,
+            trace: None,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_run_with_trace() {
+        const INPUT: &str = "run 7 --part 2 --trace json";
+        const EXPECTED: Command = Command::Run {
+            selection: Selection::Day(7),
+            part: Some(Part::Two),
+            input: InputSource::Default,
+            format: Format::Text,
+            stream: false,
+            threads: 1,
+            checked: false,
+            trace: Some(trace::Format::Json),
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_list() {
+        const INPUT: &str = "list";
+        const EXPECTED: Command = Command::List;
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_bench() {
+        const INPUT: &str = "bench all";
+        let expected = Command::Bench {
+            selection: Selection::All,
+            input: InputSource::Default,
+            baseline: PathBuf::from("target/bench/baseline.json"),
+            save: false,
+            threads: 1,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_args_bench_with_baseline() {
+        const INPUT: &str = "bench 3 --baseline before.json --save";
+        let expected = Command::Bench {
+            selection: Selection::Day(3),
+            input: InputSource::Default,
+            baseline: PathBuf::from("before.json"),
+            save: true,
+            threads: 1,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_args_fetch() {
+        const INPUT: &str = "fetch 10";
+        const EXPECTED: Command = Command::Fetch { day: 10 };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_fetch_invalid_day() {
+        const INPUT: &str = "fetch 26";
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Err("Invalid day 26".to_string()));
+    }
+
+    #[test]
+    fn test_parse_args_submit() {
+        const INPUT: &str = "submit 5 2";
+        const EXPECTED: Command = Command::Submit {
+            day: 5,
+            part: Part::Two,
+            answer: None,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_submit_with_answer() {
+        const INPUT: &str = "submit 5 1 35";
+        let expected = Command::Submit {
+            day: 5,
+            part: Part::One,
+            answer: Some("35".to_string()),
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_args_new() {
+        const INPUT: &str = "new 10";
+        const EXPECTED: Command = Command::New { day: 10 };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_readme_with_times() {
+        const INPUT: &str = "readme --times";
+        const EXPECTED: Command = Command::Readme { times: true };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_generate() {
+        const INPUT: &str = "generate 8 --size 5000 --seed 42";
+        const EXPECTED: Command = Command::Generate {
+            day: 8,
+            seed: 42,
+            size: 5000,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_generate_invalid_seed() {
+        const INPUT: &str = "generate 8 --seed -1";
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Err("Invalid seed -1".to_string()));
+    }
+
+    #[test]
+    fn test_parse_args_diff() {
+        const INPUT: &str = "diff all --seeds 1000";
+        const EXPECTED: Command = Command::Diff {
+            selection: Selection::All,
+            seeds: 1000,
+            size: None,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_check() {
+        const INPUT: &str = "check 8";
+        const EXPECTED: Command = Command::Check {
+            selection: Selection::Day(8),
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_shrink() {
+        const INPUT: &str = "shrink 4 --input -";
+        const EXPECTED: Command = Command::Shrink {
+            day: 4,
+            input: InputSource::Stdin,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_watch() {
+        const INPUT: &str = "watch 10 --interval 200";
+        const EXPECTED: Command = Command::Watch {
+            day: 10,
+            interval: Duration::from_millis(200),
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_render() {
+        const INPUT: &str = "render 3 --format svg";
+        const EXPECTED: Command = Command::Render {
+            day: 3,
+            input: InputSource::Default,
+            format: RenderFormat::Svg,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_run_with_format() {
+        const INPUT: &str = "run all --format json";
+        const EXPECTED: Command = Command::Run {
+            selection: Selection::All,
+            part: None,
+            input: InputSource::Default,
+            format: Format::Json,
+            stream: false,
+            threads: 1,
+            checked: false,
+            trace: None,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_run_with_invalid_format() {
+        const INPUT: &str = "run all --format yaml";
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Err("Invalid format yaml".to_string()));
+    }
+
+    #[test]
+    fn test_parse_args_run_with_input_path() {
+        const INPUT: &str = "run 3 --input inputs/alice.txt";
+        let expected = Command::Run {
+            selection: Selection::Day(3),
+            part: None,
+            input: InputSource::Path(PathBuf::from("inputs/alice.txt")),
+            format: Format::Text,
+            stream: false,
+            threads: 1,
+            checked: false,
+            trace: None,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_args_run_with_stdin() {
+        const INPUT: &str = "run 3 --part 1 --input -";
+        const EXPECTED: Command = Command::Run {
+            selection: Selection::Day(3),
+            part: Some(Part::One),
+            input: InputSource::Stdin,
+            format: Format::Text,
+            stream: false,
+            threads: 1,
+            checked: false,
+            trace: None,
+        };
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_args_run_all_with_input() {
+        const INPUT: &str = "run all --input -";
+        let output = parse_args(args(INPUT));
+        assert_eq!(
+            output,
+            Err(
+                "--input can only be used with a single day, use AOC_INPUT_DIR instead".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_args_invalid_part() {
+        const INPUT: &str = "run 5 --part 3";
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Err("Invalid part 3".to_string()));
+    }
+
+    #[test]
+    fn test_parse_args_unknown_command() {
+        const INPUT: &str = "walk 5";
+        let output = parse_args(args(INPUT));
+        assert_eq!(output, Err("Unknown command walk".to_string()));
+    }
+
+    #[test]
+    fn test_selection_registrations_unregistered() {
+        const INPUT: Selection = Selection::Day(25);
+        let output = INPUT
+            .registrations()
+            .map(|registrations| registrations.len());
+        assert_eq!(output, Err("Day 25 has no registered solution".to_string()));
+    }
+}
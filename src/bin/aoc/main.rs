@@ -0,0 +1,566 @@
+mod cli;
+mod output;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aoc_2023::answers::{Answers, MANIFEST_PATH};
+use aoc_2023::bench::{self, Baseline, Phase};
+use aoc_2023::client::{Client, Fetched};
+use aoc_2023::config::{Config, CONFIG_PATH};
+use aoc_2023::input::{self, InputSource};
+use aoc_2023::registry::{Answer, Registration};
+use aoc_2023::stream::StreamError;
+use aoc_2023::submissions::{Attempt, History, HISTORY_PATH};
+use aoc_2023::watch::Watcher;
+use aoc_2023::Part;
+use aoc_2023::{
+    differential, generate, num, parallel, progress, registry, render, scaffold, shrink, trace,
+};
+use cli::{Command, RenderFormat, Selection};
+use output::{Answered, Failure, Format, Printer, Row};
+
+fn main() -> ExitCode {
+    let command = match cli::parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{message}\n\n{}", cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let result = match command {
+        Command::Run {
+            selection,
+            part,
+            input,
+            format,
+            stream,
+            threads,
+            checked,
+            trace,
+        } => {
+            parallel::set_threads(threads);
+            num::set_checked(checked);
+            run(&selection, part, &input, format, stream, trace)
+        }
+        Command::Check { selection } => check(&selection),
+        Command::List => {
+            list();
+            Ok(())
+        }
+        Command::Bench {
+            selection,
+            input,
+            baseline,
+            save,
+            threads,
+        } => {
+            parallel::set_threads(threads);
+            bench(&selection, &input, &baseline, save)
+        }
+        Command::Fetch { day } => fetch(day),
+        Command::Submit { day, part, answer } => submit(day, part, answer),
+        Command::New { day } => new(day),
+        Command::Readme { times } => readme(times),
+        Command::Generate { day, seed, size } => generate(day, seed, size),
+        Command::Diff {
+            selection,
+            seeds,
+            size,
+        } => diff(&selection, seeds, size),
+        Command::Shrink { day, input } => shrink(day, &input),
+        Command::Watch { day, interval } => watch(day, interval),
+        Command::Render { day, input, format } => render(day, &input, format),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(
+    selection: &Selection,
+    part: Option<Part>,
+    source: &InputSource,
+    format: Format,
+    stream: bool,
+    trace_format: Option<trace::Format>,
+) -> Result<(), String> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let registrations = selection.registrations()?;
+    let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    quiet_panics();
+    let results = parallel::pool(workers, &registrations, |registration| {
+        // panics are caught within the collection so that the events leading up to one are kept.
+        let solve = || {
+            panic::catch_unwind(AssertUnwindSafe(|| {
+                solve_day(registration, source, &parts, stream)
+            }))
+            .unwrap_or_else(|payload| Err(Failure::Panic(parallel::panic_message(&*payload))))
+        };
+        match trace_format {
+            Some(_) => trace::collect(solve),
+            None => (solve(), vec![]),
+        }
+    });
+    let mut rows = vec![];
+    for (result, registration) in results.into_iter().zip(&registrations) {
+        let (result, events) =
+            result.unwrap_or_else(|message| (Err(Failure::Panic(message)), vec![]));
+        if let Some(trace_format) = trace_format {
+            trace::write(trace_format, registration.day(), &events, &mut io::stderr())
+                .map_err(|error| format!("Failed to write the trace: {error}"))?;
+        }
+        rows.push(Row {
+            day: registration.day(),
+            result,
+        });
+    }
+    let printed = if format == Format::Text && *selection == Selection::All {
+        print!("{}", output::summary_table(&rows));
+        Ok(())
+    } else {
+        print_rows(format, &rows)
+    };
+    printed.map_err(|error| format!("Failed to write output: {error}"))?;
+    match rows.iter().filter(|row| row.result.is_err()).count() {
+        0 => Ok(()),
+        failures => Err(format!(
+            "{failures} of {} days could not be solved",
+            rows.len()
+        )),
+    }
+}
+
+fn solve_day(
+    registration: &Registration,
+    source: &InputSource,
+    parts: &[Part],
+    stream: bool,
+) -> Result<Answered, Failure> {
+    if stream {
+        return solve_streaming(registration, source, parts).map(|answers| Answered {
+            parse_time: None,
+            answers,
+        });
+    }
+    let input = input::load(registration.day(), source)
+        .map_err(|error| Failure::Input(error.to_string()))?;
+    let solved = registration.solve(&input, parts).map_err(Failure::from)?;
+    Ok(Answered {
+        parse_time: Some(solved.parse_time),
+        answers: solved.answers,
+    })
+}
+
+fn print_rows(format: Format, rows: &[Row]) -> io::Result<()> {
+    let mut printer = Printer::new(format, io::stdout().lock(), io::stderr());
+    for row in rows {
+        match &row.result {
+            Ok(answered) => {
+                for answer in &answered.answers {
+                    printer.answer(row.day, answer)?;
+                }
+            }
+            Err(failure) => printer.failure(row.day, failure)?,
+        }
+    }
+    printer.finish()
+}
+
+fn solve_streaming(
+    registration: &Registration,
+    source: &InputSource,
+    parts: &[Part],
+) -> Result<Vec<Answer>, Failure> {
+    let day = registration.day();
+    let mut reader = input::open(day, source).map_err(|error| Failure::Input(error.to_string()))?;
+    match registration.stream(&mut reader) {
+        Some(Ok(answers)) => Ok(answers
+            .into_iter()
+            .filter(|answer| parts.contains(&answer.part))
+            .collect()),
+        Some(Err(error @ StreamError::Io(_))) => Err(Failure::Input(error.to_string())),
+        Some(Err(StreamError::Parse(error))) => Err(Failure::Parse(error)),
+        Some(Err(StreamError::Overflow(overflow))) => Err(Failure::Overflow(overflow)),
+        None => Err(Failure::Input(format!(
+            "Day {day} can't be solved as a stream"
+        ))),
+    }
+}
+
+fn check(selection: &Selection) -> Result<(), String> {
+    let answers = Answers::load(Path::new(MANIFEST_PATH))?;
+    let dir = input::input_dir();
+    let mut inputs = vec![];
+    for registration in selection.registrations()? {
+        let day = registration.day();
+        let named = input::named_inputs(&dir, day)
+            .map_err(|error| format!("Failed to list the inputs for day {day}: {error}"))?;
+        // a day may only have named inputs, but should have at least one.
+        let path = input::path_in(&dir, day);
+        if named.is_empty() || path.exists() {
+            inputs.push((registration, None, path));
+        }
+        inputs.extend(
+            named
+                .into_iter()
+                .map(|(name, path)| (registration, Some(name), path)),
+        );
+    }
+    let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    quiet_panics();
+    let results = parallel::pool(workers, &inputs, |(registration, _, path)| {
+        solve_day(
+            registration,
+            &InputSource::Path(path.clone()),
+            &Part::ALL,
+            false,
+        )
+    });
+    let (mut checked, mut mismatches, mut failures) = (0, 0, 0);
+    for ((registration, name, path), result) in inputs.iter().zip(results) {
+        let day = registration.day();
+        let label = path.strip_prefix(&dir).unwrap_or(path).display();
+        let answered = match result.unwrap_or_else(|message| Err(Failure::Panic(message))) {
+            Ok(answered) => answered,
+            Err(failure) => {
+                failures += 1;
+                println!("Day {day:02} {label}: {failure}");
+                continue;
+            }
+        };
+        for answer in answered.answers {
+            checked += 1;
+            let expected = match name {
+                Some(name) => answers.get_named(day, name, answer.part),
+                None => answers.get(day, answer.part),
+            };
+            let verdict = match expected {
+                Some(expected) if expected == answer.value => String::new(),
+                Some(expected) => {
+                    mismatches += 1;
+                    format!(", expected {expected}")
+                }
+                None => ", no known answer".to_string(),
+            };
+            println!(
+                "Day {day:02} {label} part {} => {}{verdict}",
+                answer.part, answer.value
+            );
+        }
+    }
+    match (mismatches, failures) {
+        (0, 0) => Ok(()),
+        _ => Err(format!(
+            "{mismatches} of {checked} answers don't match, and {failures} of {} inputs could not be solved",
+            inputs.len()
+        )),
+    }
+}
+
+fn list() {
+    for registration in registry::SOLUTIONS {
+        println!("Day {:02}", registration.day());
+    }
+}
+
+fn bench(
+    selection: &Selection,
+    source: &InputSource,
+    baseline_path: &Path,
+    save: bool,
+) -> Result<(), String> {
+    let mut baseline = Baseline::load(baseline_path)?;
+    let mut changed = false;
+    for registration in selection.registrations()? {
+        let day = registration.day();
+        let input = input::load(day, source).map_err(|error| error.to_string())?;
+        let measurement =
+            bench::measure(registration, &input).map_err(|error| error.to_string())?;
+        println!("Day {day:02}");
+        for phase in Phase::ALL {
+            let Some(stats) = measurement.phases.get(&phase) else {
+                continue;
+            };
+            let change = match baseline
+                .get(day, phase)
+                .map(|previous| stats.change_from(previous))
+            {
+                Some(Some(change)) => format!(" ({change:+.1}%)"),
+                Some(None) => " (n/a)".to_string(),
+                None => String::new(),
+            };
+            println!(
+                "{phase} => {:.1?} ± {:.1?} over {} runs{change}",
+                stats.median, stats.spread, stats.samples
+            );
+        }
+        if save || !baseline.contains(day) {
+            baseline.record(&measurement);
+            changed = true;
+        }
+    }
+    if changed {
+        baseline.save(baseline_path)?;
+    }
+    Ok(())
+}
+
+fn fetch(day: u8) -> Result<(), String> {
+    let client = Client::new(&Config::load(Path::new(CONFIG_PATH))?);
+    match client
+        .fetch_input(&input::input_dir(), day)
+        .map_err(|error| format!("Failed to fetch day {day}: {error}"))?
+    {
+        Fetched::Cached(path) => println!("Day {day:02} is already in {}", path.display()),
+        Fetched::Downloaded(path) => println!("Day {day:02} downloaded to {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(day: u8, part: Part, answer: Option<String>) -> Result<(), String> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => solve(day, part)?,
+    };
+    let history_path = Path::new(HISTORY_PATH);
+    let mut history = History::load(history_path)?;
+    match history.check(day, part, &answer) {
+        Ok(None) => {}
+        Ok(Some(contradiction)) => {
+            eprintln!("Warning: submitting {answer} even though {contradiction}")
+        }
+        Err(refusal) => return Err(format!("Not submitting {answer}: {refusal}")),
+    }
+    let client = Client::new(&Config::load(Path::new(CONFIG_PATH))?);
+    let submitted = client
+        .submit(day, part, &answer)
+        .map_err(|error| format!("Failed to submit day {day} part {part}: {error}"))?;
+    let time = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs());
+    history.record(Attempt {
+        day,
+        part,
+        answer: answer.clone(),
+        verdict: submitted.verdict,
+        time,
+    });
+    history.save(history_path)?;
+    println!(
+        "Day {day:02} part {part}: {answer} was {}",
+        submitted.verdict
+    );
+    println!("{}", submitted.message);
+    Ok(())
+}
+
+fn solve(day: u8, part: Part) -> Result<String, String> {
+    let registration =
+        registry::find(day).ok_or_else(|| format!("Day {day} has no registered solution"))?;
+    let input = input::load(day, &InputSource::Default).map_err(|error| error.to_string())?;
+    let solved = registration
+        .solve(&input, &[part])
+        .map_err(|error| error.to_string())?;
+    Ok(solved
+        .answers
+        .into_iter()
+        .map(|answer| answer.value)
+        .next()
+        .unwrap_or_default())
+}
+
+fn new(day: u8) -> Result<(), String> {
+    for path in scaffold::new_day(Path::new("."), day)? {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn readme(times: bool) -> Result<(), String> {
+    const README_PATH: &str = "README.md";
+    let answers = Answers::load(Path::new(MANIFEST_PATH))?;
+    let table = progress::render_table(&progress::rows(&answers, &input::input_dir(), times));
+    let readme = fs::read_to_string(README_PATH)
+        .map_err(|error| format!("Failed to read {README_PATH}: {error}"))?;
+    let readme = progress::replace_table(&readme, &table)?;
+    fs::write(README_PATH, readme)
+        .map_err(|error| format!("Failed to write {README_PATH}: {error}"))?;
+    println!("Updated the progress table in {README_PATH}");
+    Ok(())
+}
+
+fn generate(day: u8, seed: u64, size: usize) -> Result<(), String> {
+    let mut out = BufWriter::new(io::stdout().lock());
+    generate::write(day, seed, size, &mut out)
+        .ok_or_else(|| format!("Day {day} has no input generator"))?
+        .and_then(|()| out.flush())
+        .map_err(|error| format!("Failed to write output: {error}"))
+}
+
+fn diff(selection: &Selection, seeds: u64, size: Option<usize>) -> Result<(), String> {
+    let comparisons: Vec<_> = match selection {
+        Selection::All => differential::COMPARISONS.iter().collect(),
+        Selection::Day(day) => vec![differential::find(*day)
+            .ok_or_else(|| format!("Day {day} has only one implementation"))?],
+    };
+    quiet_panics();
+    let mut disagreements = 0;
+    for comparison in &comparisons {
+        match comparison.check(0..seeds, size.unwrap_or(comparison.size)) {
+            Ok(()) => {
+                let names: Vec<_> = comparison
+                    .implementations
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect();
+                println!(
+                    "Day {:02}: {} agree on {seeds} inputs",
+                    comparison.day,
+                    names.join(" and ")
+                );
+            }
+            Err(disagreement) => {
+                disagreements += 1;
+                print!("{disagreement}");
+                let reproducer = shrink::shrink_failure(
+                    comparison.day,
+                    comparison.implementations,
+                    &disagreement.input,
+                )
+                .unwrap_or(disagreement.input);
+                report_reproducer(comparison.day, &reproducer)?;
+            }
+        }
+    }
+    match disagreements {
+        0 => Ok(()),
+        n => Err(format!("{n} of {} days disagree", comparisons.len())),
+    }
+}
+
+fn shrink(day: u8, source: &InputSource) -> Result<(), String> {
+    let registration =
+        registry::find(day).ok_or(format!("Day {day} has no registered solution"))?;
+    let solution = [("solution", *registration)];
+    let implementations = differential::find(day)
+        .map(|comparison| comparison.implementations)
+        .unwrap_or(&solution);
+    let input = input::load(day, source).map_err(|error| error.to_string())?;
+    quiet_panics();
+    let reproducer = shrink::shrink_failure(day, implementations, &input)
+        .ok_or(format!("Day {day} doesn't fail on this input"))?;
+    if let Some((part, outcomes)) = differential::compare(implementations, &reproducer) {
+        println!("Day {day} part {part} fails:");
+        for (name, outcome) in outcomes {
+            println!("    {name} => {outcome}");
+        }
+    }
+    report_reproducer(day, &reproducer)
+}
+
+fn report_reproducer(day: u8, reproducer: &str) -> Result<(), String> {
+    let path = shrink::write_reproducer(Path::new(shrink::REPRODUCER_DIR), day, reproducer)?;
+    let lines = reproducer.lines().count();
+    let plural = if lines == 1 { "" } else { "s" };
+    print!(
+        "Shrunk to {lines} line{plural}, written to {}:\n{reproducer}",
+        path.display()
+    );
+    Ok(())
+}
+
+fn watch(day: u8, interval: Duration) -> Result<(), String> {
+    let registration =
+        registry::find(day).ok_or_else(|| format!("Day {day} has no registered solution"))?;
+    let dir = input::input_dir();
+    let mut watcher = Watcher::new();
+    let mut previous: Option<BTreeMap<(String, Part), String>> = None;
+    quiet_panics();
+    println!("Watching the inputs for day {day}, press Ctrl-C to stop");
+    loop {
+        let path = input::path_in(&dir, day);
+        let named = input::named_inputs(&dir, day)
+            .map_err(|error| format!("Failed to list the inputs for day {day}: {error}"))?;
+        let mut paths: Vec<_> = named.into_iter().map(|(_, path)| path).collect();
+        // the day's own input is watched even before it exists, but only solved once it does
+        // unless there's nothing else to solve.
+        let solve_own = paths.is_empty() || path.exists();
+        paths.insert(0, path);
+        if watcher.poll(paths.iter().cloned()) {
+            let paths = if solve_own { &paths[..] } else { &paths[1..] };
+            let results = parallel::pool(1, paths, |path| {
+                solve_day(
+                    registration,
+                    &InputSource::Path(path.clone()),
+                    &Part::ALL,
+                    false,
+                )
+            });
+            let mut answers = BTreeMap::new();
+            println!("Day {day:02}");
+            for (path, result) in paths.iter().zip(results) {
+                let label = path
+                    .strip_prefix(&dir)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string();
+                let answered = match result.unwrap_or_else(|message| Err(Failure::Panic(message))) {
+                    Ok(answered) => answered,
+                    Err(failure) => {
+                        println!("{label}: {failure}");
+                        continue;
+                    }
+                };
+                for answer in answered.answers {
+                    let key = (label.clone(), answer.part);
+                    let change = match previous.as_ref().map(|previous| previous.get(&key)) {
+                        Some(Some(before)) if *before != answer.value => format!(" (was {before})"),
+                        Some(None) => " (new)".to_string(),
+                        _ => String::new(),
+                    };
+                    println!("{label} part {} => {}{change}", answer.part, answer.value);
+                    answers.insert(key, answer.value);
+                }
+            }
+            println!();
+            previous = Some(answers);
+        }
+        thread::sleep(interval);
+    }
+}
+
+fn render(day: u8, source: &InputSource, format: RenderFormat) -> Result<(), String> {
+    if render::find(day).is_none() {
+        return Err(format!("Day {day} can't be rendered"));
+    }
+    let input = input::load(day, source).map_err(|error| error.to_string())?;
+    let grid = render::render(day, &input)
+        .expect("the day has a renderer")
+        .map_err(|error| error.to_string())?;
+    match format {
+        RenderFormat::Ansi => print!("{}", grid.to_ansi()),
+        RenderFormat::Svg => print!("{}", grid.to_svg()),
+    }
+    Ok(())
+}
+
+/// Panics are caught and reported as failures, so the default message for each one is noise.
+fn quiet_panics() {
+    panic::set_hook(Box::new(|_| {}));
+}
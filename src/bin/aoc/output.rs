@@ -0,0 +1,442 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use aoc_2023::json::Value;
+use aoc_2023::num::Overflow;
+use aoc_2023::registry::{Answer, SolveError};
+use aoc_2023::{NoAnswer, ParseError, Part};
+
+/// How the answers from a run are printed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Invalid format {string}")),
+        }
+    }
+}
+
+/// Why a day couldn't be solved.
+#[derive(Debug)]
+pub enum Failure {
+    Input(String),
+    Parse(ParseError),
+    /// An answer overflowed the type it was solved in, with `--checked` or with no wider type to
+    /// fall back to.
+    Overflow(Overflow),
+    /// A part has no answer for an input that parsed.
+    NoAnswer(NoAnswer),
+    /// The solver panicked with this message.
+    Panic(String),
+}
+
+impl From<SolveError> for Failure {
+    fn from(error: SolveError) -> Self {
+        match error {
+            SolveError::Parse(error) => Failure::Parse(error),
+            SolveError::Overflow(overflow) => Failure::Overflow(overflow),
+            SolveError::NoAnswer(no_answer) => Failure::NoAnswer(no_answer),
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Input(message) => write!(f, "{message}"),
+            Failure::Parse(error) => write!(f, "{error}"),
+            Failure::Overflow(overflow) => write!(f, "{overflow}"),
+            Failure::NoAnswer(no_answer) => write!(f, "{no_answer}"),
+            Failure::Panic(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+impl Failure {
+    fn to_json(&self) -> Value {
+        match self {
+            Failure::Input(message) => Value::from([
+                ("kind", Value::from("input")),
+                ("message", Value::from(message.as_str())),
+            ]),
+            Failure::Parse(error) => Value::from([
+                ("kind", Value::from("parse")),
+                ("message", Value::from(error.to_string())),
+                ("line", Value::from(error.line() as f64)),
+                ("column", Value::from(error.column() as f64)),
+                ("expected", Value::from(error.expected())),
+                ("found", Value::from(error.found())),
+            ]),
+            Failure::Overflow(overflow) => Value::from([
+                ("kind", Value::from("overflow")),
+                ("message", Value::from(overflow.to_string())),
+                ("expression", Value::from(overflow.expression())),
+                ("type", Value::from(overflow.type_name())),
+            ]),
+            Failure::NoAnswer(no_answer) => Value::from([
+                ("kind", Value::from("no answer")),
+                ("message", Value::from(no_answer.to_string())),
+                ("reason", Value::from(no_answer.reason())),
+            ]),
+            Failure::Panic(message) => Value::from([
+                ("kind", Value::from("panic")),
+                ("message", Value::from(message.as_str())),
+            ]),
+        }
+    }
+}
+
+/// Prints a record for each answer or failure in the chosen format as they come in. Failures
+/// go to `err` for text, and are records like any other for the structured formats.
+pub struct Printer<W, E> {
+    format: Format,
+    out: W,
+    err: E,
+    records: usize,
+    day: Option<u8>,
+}
+
+impl<W: Write, E: Write> Printer<W, E> {
+    pub fn new(format: Format, out: W, err: E) -> Self {
+        Self {
+            format,
+            out,
+            err,
+            records: 0,
+            day: None,
+        }
+    }
+
+    pub fn answer(&mut self, day: u8, answer: &Answer) -> io::Result<()> {
+        match self.format {
+            Format::Text => {
+                if self.day != Some(day) {
+                    writeln!(self.out, "Day {day:02}")?;
+                    self.day = Some(day);
+                }
+                writeln!(self.out, "Part {} => {}", answer.part, answer.value)
+            }
+            Format::Json => {
+                let record = Value::from([
+                    ("day", Value::from(day as f64)),
+                    ("part", Value::from(answer.part.number() as f64)),
+                    ("answer", Value::from(answer.value.as_str())),
+                    ("type", Value::from(answer.type_name)),
+                    ("elapsed_ns", Value::from(answer.time.as_nanos() as f64)),
+                ]);
+                self.json_record(record)
+            }
+            Format::Csv => {
+                self.csv_header()?;
+                writeln!(
+                    self.out,
+                    "{day},{},{},{},{},",
+                    answer.part,
+                    csv_field(&answer.value),
+                    answer.type_name,
+                    answer.time.as_nanos()
+                )
+            }
+        }
+    }
+
+    pub fn failure(&mut self, day: u8, failure: &Failure) -> io::Result<()> {
+        match self.format {
+            Format::Text => writeln!(self.err, "{failure}"),
+            Format::Json => {
+                let record = Value::from([
+                    ("day", Value::from(day as f64)),
+                    ("error", failure.to_json()),
+                ]);
+                self.json_record(record)
+            }
+            Format::Csv => {
+                self.csv_header()?;
+                writeln!(self.out, "{day},,,,,{}", csv_field(&failure.to_string()))
+            }
+        }
+    }
+
+    /// Closes off the output once every record has been printed.
+    pub fn finish(mut self) -> io::Result<()> {
+        match self.format {
+            Format::Json if self.records == 0 => writeln!(self.out, "[]"),
+            Format::Json => writeln!(self.out, "\n]"),
+            Format::Csv => self.csv_header(),
+            Format::Text => Ok(()),
+        }
+    }
+
+    fn json_record(&mut self, record: Value) -> io::Result<()> {
+        let separator = if self.records == 0 { "[\n" } else { ",\n" };
+        self.records += 1;
+        write!(self.out, "{separator}  {record}")
+    }
+
+    fn csv_header(&mut self) -> io::Result<()> {
+        if self.records == 0 {
+            writeln!(self.out, "day,part,answer,type,elapsed_ns,error")?;
+        }
+        self.records += 1;
+        Ok(())
+    }
+}
+
+/// What solving a day gave. Streamed days parse as they solve, so have no parse time.
+pub struct Row {
+    pub day: u8,
+    pub result: Result<Answered, Failure>,
+}
+
+pub struct Answered {
+    pub parse_time: Option<Duration>,
+    pub answers: Vec<Answer>,
+}
+
+/// A table with a line for each day, its answers, how long parsing and solving took and whether
+/// it could be solved, with the columns lined up.
+pub fn summary_table(rows: &[Row]) -> String {
+    let mut lines = vec![["Day", "Part 1", "Part 2", "Parse", "Solve", "Status"].map(String::from)];
+    for row in rows {
+        let day = format!("{:02}", row.day);
+        lines.push(match &row.result {
+            Ok(answered) => {
+                let value = |part| {
+                    answered
+                        .answers
+                        .iter()
+                        .find(|answer| answer.part == part)
+                        .map_or(String::new(), |answer| answer.value.clone())
+                };
+                let parse_time = answered
+                    .parse_time
+                    .map_or("-".to_string(), |time| format!("{time:.1?}"));
+                let times = answered.answers.iter().map(|answer| answer.time);
+                // every streamed answer carries the time of the one pass that gave them all.
+                let solve_time: Duration = match answered.parse_time {
+                    Some(_) => times.sum(),
+                    None => times.max().unwrap_or_default(),
+                };
+                [
+                    day,
+                    value(Part::One),
+                    value(Part::Two),
+                    parse_time,
+                    format!("{solve_time:.1?}"),
+                    "ok".to_string(),
+                ]
+            }
+            Err(failure) => [
+                day,
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                failure.to_string(),
+            ],
+        });
+    }
+    let mut widths = [0; 6];
+    for line in &lines {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut table = String::new();
+    for line in &lines {
+        let mut text = String::new();
+        for (width, cell) in widths.iter().zip(line) {
+            text.push_str(&format!("{cell:width$}  "));
+        }
+        table.push_str(text.trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2023::num::Integer;
+
+    fn answer(part: Part, value: &str) -> Answer {
+        Answer {
+            part,
+            value: value.to_string(),
+            type_name: "u32",
+            time: Duration::from_micros(15),
+        }
+    }
+
+    fn print(format: Format) -> (String, String) {
+        let failure = Failure::Parse(
+            ParseError::new(
+                "Game 1: 3 purple",
+                &"Game 1: 3 purple"[10..],
+                "red, green or blue",
+            )
+            .on_day(2),
+        );
+        let (mut out, mut err) = (vec![], vec![]);
+        let mut printer = Printer::new(format, &mut out, &mut err);
+        printer.answer(1, &answer(Part::One, "142")).unwrap();
+        printer.answer(1, &answer(Part::Two, "281")).unwrap();
+        printer.failure(2, &failure).unwrap();
+        printer.finish().unwrap();
+        (
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_print_text() {
+        const EXPECTED_OUT: &str = "Day 01\nPart 1 => 142\nPart 2 => 281\n";
+        const EXPECTED_ERR: &str =
+            "day 2, line 1, column 11: expected red, green or blue, found \"purple\"\n";
+        let output = print(Format::Text);
+        assert_eq!(output, (EXPECTED_OUT.to_string(), EXPECTED_ERR.to_string()));
+    }
+
+    #[test]
+    fn test_print_json() {
+        const EXPECTED: &str = r#"[
+  {"answer":"142","day":1,"elapsed_ns":15000,"part":1,"type":"u32"},
+  {"answer":"281","day":1,"elapsed_ns":15000,"part":2,"type":"u32"},
+  {"day":2,"error":{"column":11,"expected":"red, green or blue","found":"purple","kind":"parse","line":1,"message":"day 2, line 1, column 11: expected red, green or blue, found \"purple\""}}
+]
+"#;
+        let (output, _) = print(Format::Json);
+        assert_eq!(output, EXPECTED);
+        assert!(Value::parse(&output).is_ok());
+    }
+
+    #[test]
+    fn test_print_json_empty() {
+        let mut out = vec![];
+        Printer::new(Format::Json, &mut out, io::sink())
+            .finish()
+            .unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "[]\n");
+    }
+
+    #[test]
+    fn test_summary_table() {
+        const EXPECTED: &str = "\
+Day  Part 1  Part 2  Parse   Solve   Status
+01   142     281     -       15.0µs  ok
+02                                   panicked: attempt to add with overflow
+03   4361    467835  15.0µs  30.0µs  ok
+";
+        let rows = [
+            Row {
+                day: 1,
+                result: Ok(Answered {
+                    parse_time: None,
+                    answers: vec![answer(Part::One, "142"), answer(Part::Two, "281")],
+                }),
+            },
+            Row {
+                day: 2,
+                result: Err(Failure::Panic("attempt to add with overflow".to_string())),
+            },
+            Row {
+                day: 3,
+                result: Ok(Answered {
+                    parse_time: Some(Duration::from_micros(15)),
+                    answers: vec![answer(Part::One, "4361"), answer(Part::Two, "467835")],
+                }),
+            },
+        ];
+        let output = summary_table(&rows);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_print_csv() {
+        const EXPECTED: &str = "\
+day,part,answer,type,elapsed_ns,error
+1,1,142,u32,15000,
+1,2,281,u32,15000,
+2,,,,,\"day 2, line 1, column 11: expected red, green or blue, found \"\"purple\"\"\"
+";
+        let (output, _) = print(Format::Csv);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_print_json_widened_type() {
+        // part 1 reads 11 from each line, which fits in day 1's u16, and part 2 reads 99, which
+        // doesn't.
+        let input = "nine1nine\n".repeat(700);
+        let solved = aoc_2023::registry::find(1)
+            .unwrap()
+            .solve(&input, &Part::ALL)
+            .unwrap();
+        let mut out = vec![];
+        let mut printer = Printer::new(Format::Json, &mut out, vec![]);
+        for answer in &solved.answers {
+            printer.answer(1, answer).unwrap();
+        }
+        printer.finish().unwrap();
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains(r#""answer":"7700","#), "{output}");
+        assert!(output.contains(r#""part":1,"type":"u16""#), "{output}");
+        assert!(output.contains(r#""answer":"69300","#), "{output}");
+        assert!(output.contains(r#""part":2,"type":"u128""#), "{output}");
+    }
+
+    #[test]
+    fn test_print_json_overflow() {
+        const EXPECTED: &str = r#"[
+  {"day":1,"error":{"expression":"65439 + 99","kind":"overflow","message":"day 1, part 1: 65439 + 99 overflows u16","type":"u16"}}
+]
+"#;
+        let overflow = 65439u16.try_add(99).unwrap_err();
+        let failure = Failure::Overflow(overflow.on_day(1).in_part(Part::One));
+        let mut out = vec![];
+        let mut printer = Printer::new(Format::Json, &mut out, vec![]);
+        printer.failure(1, &failure).unwrap();
+        printer.finish().unwrap();
+        let output = String::from_utf8(out).unwrap();
+        assert_eq!(output, EXPECTED);
+        assert!(Value::parse(&output).is_ok());
+    }
+
+    #[test]
+    fn test_print_json_no_answer() {
+        const EXPECTED: &str = r#"[
+  {"day":8,"error":{"kind":"no answer","message":"day 8, part 1: there are no moves to walk with","reason":"there are no moves to walk with"}}
+]
+"#;
+        let no_answer = NoAnswer::new("there are no moves to walk with".to_string());
+        let failure = Failure::NoAnswer(no_answer.on_day(8).in_part(Part::One));
+        let mut out = vec![];
+        let mut printer = Printer::new(Format::Json, &mut out, vec![]);
+        printer.failure(8, &failure).unwrap();
+        printer.finish().unwrap();
+        let output = String::from_utf8(out).unwrap();
+        assert_eq!(output, EXPECTED);
+        assert!(Value::parse(&output).is_ok());
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Config, SESSION_VARIABLE};
+use crate::http::{self, HttpError};
+use crate::input;
+use crate::submissions::Verdict;
+use crate::Part;
+
+const USER_AGENT: &str = "aoc-2023 (a Rust solutions runner)";
+
+/// A client for the puzzle site, or anything standing in for it at the configured base URL.
+#[derive(Clone, Debug)]
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Http(HttpError),
+    Status { status: u16, body: String },
+    UnexpectedResponse(String),
+    Write { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "No session token, set {SESSION_VARIABLE} or add session to the config file"
+            ),
+            ClientError::Http(error) => write!(f, "{error}"),
+            ClientError::Status { status, body } => {
+                write!(f, "Server responded with {status}")?;
+                match body.lines().map(str::trim).find(|line| !line.is_empty()) {
+                    Some(line) => write!(f, ": {line}"),
+                    None => Ok(()),
+                }
+            }
+            ClientError::UnexpectedResponse(message) => {
+                write!(f, "Could not find a verdict in the response: {message}")
+            }
+            ClientError::Write { path, error } => {
+                write!(f, "Failed to write {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<HttpError> for ClientError {
+    fn from(error: HttpError) -> Self {
+        ClientError::Http(error)
+    }
+}
+
+/// Where a fetched input ended up, and whether it had to be downloaded.
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// The verdict on a submitted answer, along with the site's explanation of it.
+#[derive(Debug, PartialEq)]
+pub struct Submitted {
+    pub verdict: Verdict,
+    pub message: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            session: config.session.clone(),
+        }
+    }
+
+    /// Downloads the input for a day.
+    pub fn download_input(&self, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/day/{day}/input", self.base_url);
+        let cookie = self.cookie()?;
+        let response = http::get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])?;
+        match response.status {
+            200 => Ok(response.body),
+            status => Err(ClientError::Status {
+                status,
+                body: response.body,
+            }),
+        }
+    }
+
+    /// Makes sure the input for a day is in `dir`, only downloading it if it isn't already there.
+    ///
+    /// An empty file, like the one `aoc new` leaves for the day, doesn't count as being there.
+    pub fn fetch_input(&self, dir: &Path, day: u8) -> Result<Fetched, ClientError> {
+        let path = input::path_in(dir, day);
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+        let input = self.download_input(day)?;
+        fs::create_dir_all(dir)
+            .and_then(|_| fs::write(&path, input))
+            .map_err(|error| ClientError::Write {
+                path: path.clone(),
+                error,
+            })?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Submits an answer for a part of a day.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Submitted, ClientError> {
+        let url = format!("{}/day/{day}/answer", self.base_url);
+        let cookie = self.cookie()?;
+        let level = part.to_string();
+        let response = http::post_form(
+            &url,
+            &[("Cookie", &cookie), ("User-Agent", USER_AGENT)],
+            &[("level", &level), ("answer", answer)],
+        )?;
+        if response.status != 200 {
+            return Err(ClientError::Status {
+                status: response.status,
+                body: response.body,
+            });
+        }
+        let message = article_text(&response.body);
+        match Verdict::from_response(&response.body) {
+            Some(verdict) => Ok(Submitted { verdict, message }),
+            None => Err(ClientError::UnexpectedResponse(message)),
+        }
+    }
+
+    fn cookie(&self) -> Result<String, ClientError> {
+        let session = self.session.as_ref().ok_or(ClientError::MissingSession)?;
+        Ok(format!("session={session}"))
+    }
+}
+
+/// The text of the `<article>` in a page, which is where the site explains a verdict, without
+/// any markup.
+fn article_text(body: &str) -> String {
+    let article = body
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::http::stand_in;
+
+    fn client(base_url: &str) -> Client {
+        Client::new(&Config {
+            session: Some("abc123".to_string()),
+            base_url: base_url.to_string(),
+        })
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-2023-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (url, server) = stand_in::serve(vec![(200, "Time: 7 15 30\n")]);
+        let dir = temp_dir("fetch");
+        let output = client(&format!("{url}/2023/"))
+            .fetch_input(&dir, 6)
+            .unwrap();
+        let requests = server.join().unwrap();
+        let input = fs::read_to_string(dir.join("day06.txt")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(output, Fetched::Downloaded(dir.join("day06.txt")));
+        assert_eq!(input, "Time: 7 15 30\n");
+        assert!(requests[0].starts_with("GET /2023/day/6/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc123\r\n"));
+    }
+
+    #[test]
+    fn test_fetch_input_cached() {
+        let (url, server) = stand_in::serve(vec![]);
+        let dir = temp_dir("cached");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day06.txt"), "Time: 7 15 30\n").unwrap();
+        let output = client(&url).fetch_input(&dir, 6).unwrap();
+        let requests = server.join().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(output, Fetched::Cached(dir.join("day06.txt")));
+        assert!(requests.is_empty());
+    }
+
+    #[test]
+    fn test_fetch_input_empty() {
+        let (url, server) = stand_in::serve(vec![(200, "Time: 7 15 30\n")]);
+        let dir = temp_dir("empty");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day06.txt"), "").unwrap();
+        let output = client(&url).fetch_input(&dir, 6).unwrap();
+        let requests = server.join().unwrap();
+        let input = fs::read_to_string(dir.join("day06.txt")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(output, Fetched::Downloaded(dir.join("day06.txt")));
+        assert_eq!(input, "Time: 7 15 30\n");
+        assert_eq!(requests.len(), 1);
+    }
+
+    #[test]
+    fn test_fetch_input_not_found() {
+        const EXPECTED: &str = "Server responded with 404: Please don't repeatedly request this endpoint before it unlocks!";
+        let (url, server) = stand_in::serve(vec![(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!\n",
+        )]);
+        let dir = temp_dir("not-found");
+        let output = client(&url).fetch_input(&dir, 25).unwrap_err().to_string();
+        server.join().unwrap();
+        assert_eq!(output, EXPECTED);
+        assert!(!dir.join("day25.txt").exists());
+    }
+
+    #[test]
+    fn test_submit() {
+        const RESPONSE: &str = "<html><main><article><p>That's not the right answer; \
+            your answer is too low. Please wait one minute before trying again. \
+            <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article></main></html>";
+        let expected = Submitted {
+            verdict: Verdict::TooLow,
+            message: "That's not the right answer; your answer is too low. Please wait one \
+                minute before trying again. [Return to Day 5]"
+                .to_string(),
+        };
+        let (url, server) = stand_in::serve(vec![(200, RESPONSE)]);
+        let output = client(&url).submit(5, Part::Two, "46").unwrap();
+        let requests = server.join().unwrap();
+        assert_eq!(output, expected);
+        assert!(requests[0].starts_with("POST /day/5/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("level=2&answer=46"));
+    }
+
+    #[test]
+    fn test_submit_unexpected_response() {
+        const RESPONSE: &str =
+            "<article><p>You don't seem to be solving the right level.</p></article>";
+        const EXPECTED: &str = "Could not find a verdict in the response: You don't seem to be solving the right level.";
+        let (url, server) = stand_in::serve(vec![(200, RESPONSE)]);
+        let output = client(&url)
+            .submit(5, Part::One, "35")
+            .unwrap_err()
+            .to_string();
+        server.join().unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_download_input_without_session() {
+        let input = Client::new(&Config::default());
+        let output = input.download_input(1).unwrap_err();
+        assert!(matches!(output, ClientError::MissingSession));
+    }
+}
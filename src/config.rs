@@ -0,0 +1,120 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::ParseError;
+
+/// Path of the optional config file, relative to the directory the binary is run from. It holds
+/// the session token, so it is ignored by git.
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+/// Environment variable holding the session token, taking priority over the config file.
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+/// Environment variable holding the base URL, taking priority over the config file.
+pub const BASE_URL_VARIABLE: &str = "AOC_BASE_URL";
+
+/// The puzzles for this year, which every request is made relative to.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2023";
+
+/// Settings for talking to the puzzle site, read from a config file of the form:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com/2023"
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config file if there is one, then applies any overrides from the environment.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(text) => {
+                Self::parse(&text).map_err(|error| format!("{}: {error}", path.display()))?
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(error) => return Err(format!("Failed to read {}: {error}", path.display())),
+        };
+        if let Ok(session) = env::var(SESSION_VARIABLE) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VARIABLE) {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut config = Self::default();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::new(text, line, "a key = \"value\" pair"))?;
+            let (key, value) = (key.trim(), value.trim());
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .ok_or_else(|| ParseError::new(text, value, "a quoted value"))?
+                .to_string();
+            match key {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                _ => return Err(ParseError::new(text, key, "session or base_url")),
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        const INPUT: &str = "
+        # from the browser's cookies
+        session = \"abc123\"
+        base_url = \"http://127.0.0.1:8080/2023\"
+        ";
+        let expected = Config {
+            session: Some("abc123".to_string()),
+            base_url: "http://127.0.0.1:8080/2023".to_string(),
+        };
+        let output = Config::parse(INPUT).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        const INPUT: &str = "";
+        let output = Config::parse(INPUT).unwrap();
+        assert_eq!(output, Config::default());
+    }
+
+    #[test]
+    fn test_parse_unquoted_value() {
+        const INPUT: &str = "session = abc123";
+        const EXPECTED: &str = "line 1, column 11: expected a quoted value, found \"abc123\"";
+        let output = Config::parse(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+}
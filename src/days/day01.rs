@@ -0,0 +1,193 @@
+use crate::num::{self, Integer, Overflow, Widened};
+use crate::parallel;
+use crate::stream::Streaming;
+use crate::{ParseError, Part, Solution, SolveError};
+use alloc::vec::Vec;
+
+type IntegerType = u16;
+
+const NUMBER_PATTERNS: &[(&str, u8)] = &[
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+const WORD_PATTERNS: &[(&str, u8)] = &[
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+    type Parsed<'a> = Vec<&'a str>;
+    type Part1 = Widened<IntegerType>;
+    type Part2 = Widened<IntegerType>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_lines(input).map_err(|error| error.on_day(Self::DAY))
+    }
+
+    fn solve_part_1(lines: &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(num::widen(|| solve_part_1(lines), || solve_part_1(lines))?)
+    }
+
+    fn solve_part_2(lines: &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(num::widen(|| solve_part_2(lines), || solve_part_2(lines))?)
+    }
+}
+
+impl Streaming for Day01 {
+    type Line<'a> = &'a str;
+    type State = (Widened<IntegerType>, Widened<IntegerType>);
+
+    fn parse_line(line: &str) -> Result<Self::Line<'_>, ParseError> {
+        parse_line(line)
+    }
+
+    fn fold_line((part_1, part_2): &mut Self::State, line: Self::Line<'_>) -> Result<(), Overflow> {
+        let value_1 = process_line(line, NUMBER_PATTERNS.iter());
+        let value_2 = process_line(line, NUMBER_PATTERNS.iter().chain(WORD_PATTERNS));
+        *part_1 = part_1
+            .plus(Widened::Narrow(value_1.into()))
+            .map_err(|overflow| overflow.in_part(Part::One))?;
+        *part_2 = part_2
+            .plus(Widened::Narrow(value_2.into()))
+            .map_err(|overflow| overflow.in_part(Part::Two))?;
+        Ok(())
+    }
+
+    fn finish(state: Self::State) -> (Self::Part1, Self::Part2) {
+        state
+    }
+}
+
+fn solve_part_1<N: Integer>(lines: &[&str]) -> Result<N, Overflow> {
+    process_lines(lines, NUMBER_PATTERNS.iter())
+}
+
+fn solve_part_2<N: Integer>(lines: &[&str]) -> Result<N, Overflow> {
+    process_lines(lines, NUMBER_PATTERNS.iter().chain(WORD_PATTERNS))
+}
+
+fn parse_lines(input: &str) -> Result<Vec<&str>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            parse_line(line).map_err(|error| error.within(input, line))
+        })
+        .collect()
+}
+
+fn parse_line(line: &str) -> Result<&str, ParseError> {
+    match line.find(|c: char| !c.is_ascii_alphanumeric()) {
+        Some(index) => Err(ParseError::new(line, &line[index..], "a letter or digit")),
+        None => Ok(line),
+    }
+}
+
+fn process_line<'a>(input: &str, patterns: impl Iterator<Item = &'a (&'a str, u8)>) -> u8 {
+    let mut leftmost_index = usize::MAX;
+    let mut leftmost_value = 0;
+    let mut rightmost_index = usize::MIN;
+    let mut rightmost_value = 0;
+
+    for (pattern, pattern_value) in patterns {
+        if let Some(index) = input.find(pattern) {
+            if index < leftmost_index {
+                leftmost_index = index;
+                leftmost_value = *pattern_value;
+            }
+        }
+
+        if let Some(index) = input.rfind(pattern) {
+            let index = index + pattern.len();
+            if index > rightmost_index {
+                rightmost_index = index;
+                rightmost_value = *pattern_value;
+            }
+        }
+    }
+
+    leftmost_value * 10 + rightmost_value
+}
+
+fn process_lines<'a, N: Integer>(
+    lines: &[&str],
+    patterns: impl Iterator<Item = &'a (&'a str, u8)> + Clone + Sync,
+) -> Result<N, Overflow> {
+    let values = parallel::map(lines, |line| process_line(line, patterns.clone()));
+    num::sum(values.into_iter().map(N::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream;
+
+    #[test]
+    fn test_solve_part_1() {
+        const INPUT: &str = "
+        1abc2
+        pqr3stu8vwx
+        a1b2c3d4e5f
+        treb7uchet
+        ";
+        const EXPECTED: IntegerType = 142;
+        let output = solve_part_1(&parse_lines(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        const INPUT: &str = "
+        two1nine
+        eightwothree
+        abcone2threexyz
+        xtwone3four
+        4nineeightseven2
+        zoneight234
+        7pqrstsixteen
+        ";
+        const EXPECTED: IntegerType = 281;
+        let output = solve_part_2(&parse_lines(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_widens_on_overflow() {
+        let input = "9nine\n".repeat(700);
+        let lines = parse_lines(&input).unwrap();
+        assert_eq!(Day01::solve_part_1(&lines), Ok(Widened::Wide(69300)));
+        assert_eq!(Day01::solve_part_2(&lines), Ok(Widened::Wide(69300)));
+        let output = stream::solve::<Day01>(&mut input.as_bytes()).unwrap();
+        assert_eq!(output, (Widened::Wide(69300), Widened::Wide(69300)));
+    }
+
+    #[test]
+    fn test_parse_lines_invalid_character() {
+        const INPUT: &str = "
+        1abc2
+        pqr3-stu8vwx
+        ";
+        const EXPECTED: &str = "line 3, column 13: expected a letter or digit, found \"-stu8vwx\"";
+        let output = parse_lines(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+}
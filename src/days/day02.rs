@@ -0,0 +1,426 @@
+use alloc::vec::Vec;
+use core::cmp;
+use core::str::FromStr;
+
+use crate::num::{self, Integer, Overflow, Widened};
+use crate::parallel;
+use crate::stream::Streaming;
+use crate::trace;
+use crate::{ParseError, Part, Solution, SolveError};
+
+type IntegerType = u32;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+    type Parsed<'a> = Vec<Game>;
+    type Part1 = Widened<IntegerType>;
+    type Part2 = Widened<IntegerType>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_games(input).map_err(|error| error.on_day(Self::DAY))
+    }
+
+    fn solve_part_1(games: &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(num::widen(|| solve_part_1(games), || solve_part_1(games))?)
+    }
+
+    fn solve_part_2(games: &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(num::widen(|| solve_part_2(games), || solve_part_2(games))?)
+    }
+}
+
+impl Streaming for Day02 {
+    type Line<'a> = Game;
+    type State = (Widened<IntegerType>, Widened<IntegerType>);
+
+    fn parse_line(line: &str) -> Result<Self::Line<'_>, ParseError> {
+        line.parse()
+    }
+
+    fn fold_line((part_1, part_2): &mut Self::State, game: Self::Line<'_>) -> Result<(), Overflow> {
+        let id = if game.valid() { game.id.0 } else { 0 };
+        *part_1 = part_1
+            .plus(Widened::Narrow(id))
+            .map_err(|overflow| overflow.in_part(Part::One))?;
+        *part_2 = num::widen(|| game.power(), || game.power())
+            .and_then(|power| part_2.plus(power))
+            .map_err(|overflow| overflow.in_part(Part::Two))?;
+        Ok(())
+    }
+
+    fn finish(state: Self::State) -> (Self::Part1, Self::Part2) {
+        state
+    }
+}
+
+fn solve_part_1<N: Integer + From<u32>>(games: &[Game]) -> Result<N, Overflow> {
+    num::sum(
+        games
+            .iter()
+            .filter(|game| game.valid())
+            .map(|game| N::from(game.id.0)),
+    )
+}
+
+fn solve_part_2<N: Integer + From<u32>>(games: &[Game]) -> Result<N, Overflow> {
+    games
+        .iter()
+        .try_fold(N::ZERO, |total, game| total.try_add(game.power()?))
+}
+
+fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    let lines: Vec<_> = input.trim().lines().map(str::trim).collect();
+    parallel::map(&lines, |line| {
+        line.parse()
+            .map_err(|error: ParseError| error.within(input, line))
+    })
+    .into_iter()
+    .collect()
+}
+
+#[derive(Debug, PartialEq)]
+struct GameId(u32);
+
+impl FromStr for GameId {
+    type Err = ParseError;
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let id = string
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::new(string, string, "\"Game \""))?;
+        let id = id
+            .parse()
+            .map_err(|_| ParseError::new(string, id, "a game id"))?;
+        Ok(Self(id))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Rgb {
+    r: u32,
+    g: u32,
+    b: u32,
+}
+
+impl Rgb {
+    /// The first color with more cubes than the bag holds, with its count and the limit.
+    fn exceeded(&self) -> Option<(&'static str, u32, u32)> {
+        const RED_COUNT: u32 = 12;
+        const GREEN_COUNT: u32 = 13;
+        const BLUE_COUNT: u32 = 14;
+        [
+            ("red", self.r, RED_COUNT),
+            ("green", self.g, GREEN_COUNT),
+            ("blue", self.b, BLUE_COUNT),
+        ]
+        .into_iter()
+        .find(|(_, count, limit)| count > limit)
+    }
+
+    fn maximum(&self, other: &Self) -> Self {
+        Self {
+            r: cmp::max(self.r, other.r),
+            g: cmp::max(self.g, other.g),
+            b: cmp::max(self.b, other.b),
+        }
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = ParseError;
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut value = Self::default();
+        for part in string.split(", ") {
+            let (number, color) = part
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(string, part, "a count followed by a color"))?;
+            let number = number
+                .parse()
+                .map_err(|_| ParseError::new(string, number, "a count"))?;
+            match color {
+                "red" => value.r = number,
+                "green" => value.g = number,
+                "blue" => value.b = number,
+                _ => return Err(ParseError::new(string, color, "red, green or blue")),
+            }
+        }
+        Ok(value)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    id: GameId,
+    rgbs: Vec<Rgb>,
+}
+
+impl Game {
+    fn valid(&self) -> bool {
+        for (draw, rgb) in self.rgbs.iter().enumerate() {
+            if let Some((color, count, limit)) = rgb.exceeded() {
+                trace::event(
+                    "invalid draw",
+                    &[
+                        ("game", &self.id.0),
+                        ("draw", &(draw + 1)),
+                        ("color", &color),
+                        ("count", &count),
+                        ("limit", &limit),
+                    ],
+                );
+                return false;
+            }
+        }
+        true
+    }
+
+    fn power<N: Integer + From<u32>>(&self) -> Result<N, Overflow> {
+        let Rgb { r, g, b } = self
+            .rgbs
+            .iter()
+            .fold(Rgb::default(), |state, elem| state.maximum(elem));
+        N::from(r).try_mul(N::from(g))?.try_mul(N::from(b))
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (id, draws) = string
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at_end(string, "\": \" after the game id"))?;
+        let id = id
+            .parse()
+            .map_err(|error: ParseError| error.within(string, id))?;
+        let mut rgbs = Vec::with_capacity(3);
+        for rgb in draws.split("; ") {
+            rgbs.push(
+                rgb.parse()
+                    .map_err(|error: ParseError| error.within(string, rgb))?,
+            );
+        }
+        Ok(Self { id, rgbs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream;
+
+    #[test]
+    fn test_parse_game_id() {
+        const INPUT: &str = "Game 1";
+        const EXPECTED: GameId = GameId(1);
+        let output: GameId = INPUT.parse().unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_rgb_blue_red() {
+        const INPUT: &str = "3 blue, 4 red";
+        const EXPECTED: Rgb = Rgb { r: 4, g: 0, b: 3 };
+        let output: Rgb = INPUT.parse().unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_rgb_red_green_blue() {
+        const INPUT: &str = "1 red, 2 green, 6 blue";
+        const EXPECTED: Rgb = Rgb { r: 1, g: 2, b: 6 };
+        let output: Rgb = INPUT.parse().unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_rgb_green() {
+        const INPUT: &str = "2 green";
+        const EXPECTED: Rgb = Rgb { r: 0, g: 2, b: 0 };
+        let output: Rgb = INPUT.parse().unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_game() {
+        const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let expected = Game {
+            id: GameId(1),
+            rgbs: vec![
+                Rgb { r: 4, g: 0, b: 3 },
+                Rgb { r: 1, g: 2, b: 6 },
+                Rgb { r: 0, g: 2, b: 0 },
+            ],
+        };
+        let output: Game = INPUT.parse().unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_parse_game_id_missing_prefix() {
+        const INPUT: &str = "Gmae 1";
+        const EXPECTED: &str = "line 1, column 1: expected \"Game \", found \"Gmae 1\"";
+        let output = INPUT.parse::<GameId>().unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_rgb_invalid_color() {
+        const INPUT: &str = "3 blue, 4 purple";
+        const EXPECTED: &str = "line 1, column 11: expected red, green or blue, found \"purple\"";
+        let output = INPUT.parse::<Rgb>().unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_games_invalid_count() {
+        const INPUT: &str = "
+        Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; three green, 4 blue, 1 red; 1 green, 1 blue
+        ";
+        const EXPECTED: &str = "line 3, column 34: expected a count, found \"three\"";
+        let output = parse_games(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_rgb_not_exceeded() {
+        const INPUT: Rgb = Rgb { r: 10, g: 9, b: 8 };
+        const EXPECTED: Option<(&str, u32, u32)> = None;
+        let output = INPUT.exceeded();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_rgb_exceeded_red() {
+        const INPUT: Rgb = Rgb { r: 13, g: 9, b: 8 };
+        const EXPECTED: Option<(&str, u32, u32)> = Some(("red", 13, 12));
+        let output = INPUT.exceeded();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_game_valid() {
+        let input = Game {
+            id: GameId(0),
+            rgbs: vec![
+                Rgb { r: 0, g: 2, b: 1 },
+                Rgb { r: 1, g: 3, b: 4 },
+                Rgb { r: 0, g: 1, b: 1 },
+            ],
+        };
+        const EXPECTED: bool = true;
+        let output = input.valid();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_game_not_valid() {
+        let input = Game {
+            id: GameId(0),
+            rgbs: vec![
+                Rgb { r: 20, g: 8, b: 6 },
+                Rgb { r: 4, g: 13, b: 5 },
+                Rgb { r: 1, g: 5, b: 0 },
+            ],
+        };
+        const EXPECTED: bool = false;
+        let output = input.valid();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_rgb_exceeded() {
+        const INPUT: Rgb = Rgb { r: 4, g: 14, b: 15 };
+        const EXPECTED: Option<(&str, u32, u32)> = Some(("green", 14, 13));
+        let output = INPUT.exceeded();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_game_valid_trace() {
+        const INPUT: &str = "Game 3: 8 green, 6 blue; 5 blue, 4 red, 13 green; 5 green, 20 red";
+        const EXPECTED: &str = "invalid draw game=3 draw=3 color=red count=20 limit=12";
+        let game: Game = INPUT.parse().unwrap();
+        let (output, events) = trace::collect(|| game.valid());
+        assert!(!output);
+        let events: Vec<_> = events.iter().map(ToString::to_string).collect();
+        assert_eq!(events, [EXPECTED]);
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        const INPUT: &str = "
+        Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+        ";
+        const EXPECTED: IntegerType = 8;
+        let output = solve_part_1::<IntegerType>(&parse_games(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_rgb_maximum() {
+        const INPUT_1: Rgb = Rgb { r: 12, g: 8, b: 14 };
+        const INPUT_2: Rgb = Rgb { r: 10, g: 17, b: 7 };
+        const EXPECTED: Rgb = Rgb {
+            r: 12,
+            g: 17,
+            b: 14,
+        };
+        let output = INPUT_1.maximum(&INPUT_2);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_game_power() {
+        let input = Game {
+            id: GameId(3),
+            rgbs: vec![
+                Rgb { r: 20, g: 8, b: 6 },
+                Rgb { r: 4, g: 13, b: 5 },
+                Rgb { r: 1, g: 5, b: 0 },
+            ],
+        };
+        const EXPECTED: IntegerType = 1560;
+        let output = input.power();
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        const INPUT: &str = "
+        Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+        ";
+        const EXPECTED: IntegerType = 2286;
+        let output = solve_part_2::<IntegerType>(&parse_games(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_widens_on_overflow() {
+        // the ids of the possible games add up to 5000000000 and the power of the last one is
+        // 8000000000, both past the largest u32, while every id and count fits.
+        const INPUT: &str = "\
+Game 4000000000: 1 red, 1 green, 1 blue
+Game 1000000000: 1 red, 1 green, 1 blue
+Game 3: 2000 red, 2000 green, 2000 blue
+";
+        let games = parse_games(INPUT).unwrap();
+        assert_eq!(Day02::solve_part_1(&games), Ok(Widened::Wide(5000000000)));
+        assert_eq!(Day02::solve_part_2(&games), Ok(Widened::Wide(8000000002)));
+        let output = stream::solve::<Day02>(&mut INPUT.as_bytes()).unwrap();
+        assert_eq!(
+            output,
+            (Widened::Wide(5000000000), Widened::Wide(8000000002))
+        );
+    }
+}
@@ -0,0 +1,625 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::Peekable;
+
+use crate::num::{self, Integer, Overflow, Widened};
+use crate::render::{Colour, Grid, Style};
+use crate::{ParseError, Solution, SolveError};
+
+type IntegerType = u32;
+
+#[derive(Debug, PartialEq)]
+pub struct NumberSection {
+    value: u32,
+    start_x: usize,
+    end_x: usize,
+    y: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SymbolSection {
+    symbol: char,
+    x: usize,
+    y: usize,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+    type Parsed<'a> = (Vec<NumberSection>, Vec<SymbolSection>);
+    type Part1 = Widened<IntegerType>;
+    type Part2 = Widened<IntegerType>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        read_sections_from_string(input).map_err(|error| error.on_day(Self::DAY))
+    }
+
+    fn solve_part_1(
+        (number_sections, symbol_sections): &Self::Parsed<'_>,
+    ) -> Result<Self::Part1, SolveError> {
+        Ok(num::widen(
+            || solve_part_1(number_sections, symbol_sections),
+            || solve_part_1(number_sections, symbol_sections),
+        )?)
+    }
+
+    fn solve_part_2(
+        (number_sections, symbol_sections): &Self::Parsed<'_>,
+    ) -> Result<Self::Part2, SolveError> {
+        Ok(num::widen(
+            || solve_part_2(number_sections, symbol_sections),
+            || solve_part_2(number_sections, symbol_sections),
+        )?)
+    }
+}
+
+fn solve_part_1<N: Integer + From<u32>>(
+    number_sections: &[NumberSection],
+    symbol_sections: &[SymbolSection],
+) -> Result<N, Overflow> {
+    num::sum(number_sections.iter().filter_map(|number_section| {
+        if symbol_sections.iter().any(|symbol_section| {
+            is_number_section_adjacent_to_symbol_section(number_section, symbol_section)
+        }) {
+            Some(N::from(number_section.value))
+        } else {
+            None
+        }
+    }))
+}
+
+fn solve_part_2<N: Integer + From<u32>>(
+    number_sections: &[NumberSection],
+    symbol_sections: &[SymbolSection],
+) -> Result<N, Overflow> {
+    symbol_sections
+        .iter()
+        .filter_map(|symbol_section| gear_numbers(number_sections, symbol_section))
+        .try_fold(N::ZERO, |total, (number_section_1, number_section_2)| {
+            let ratio = N::from(number_section_1.value).try_mul(N::from(number_section_2.value))?;
+            total.try_add(ratio)
+        })
+}
+
+/// The two numbers next to a symbol if it is a gear, which is when it has exactly two.
+fn gear_numbers<'a>(
+    number_sections: &'a [NumberSection],
+    symbol_section: &SymbolSection,
+) -> Option<(&'a NumberSection, &'a NumberSection)> {
+    let mut adjacent_numbers = number_sections.iter().filter(|number_section| {
+        is_number_section_adjacent_to_symbol_section(number_section, symbol_section)
+    });
+    adjacent_numbers.next().and_then(|number_section_1| {
+        adjacent_numbers.next().and_then(|number_section_2| {
+            if adjacent_numbers.next().is_some() {
+                None
+            } else {
+                Some((number_section_1, number_section_2))
+            }
+        })
+    })
+}
+
+/// Draws the schematic with the part numbers that touch a symbol, the numbers that don't, and
+/// the gears and the two numbers that make up their ratios each in their own colour.
+pub fn render(input: &str) -> Result<Grid, ParseError> {
+    const PART: Style = Style::colour(Colour::Green);
+    const ISOLATED: Style = Style::colour(Colour::Grey);
+    const SYMBOL: Style = Style::colour(Colour::Cyan);
+    const GEAR: Style = Style::colour(Colour::Yellow).bold();
+    const RATIO: Style = Style::colour(Colour::Yellow);
+    let (number_sections, symbol_sections) = Day03::parse(input)?;
+    // the same characters the parser sees, so the coordinates line up.
+    let rows: Vec<String> = input
+        .trim()
+        .lines()
+        .map(|line| line.chars().filter(|c| !c.is_ascii_whitespace()).collect())
+        .collect();
+    let mut grid = Grid::from_rows(rows.iter().map(String::as_str));
+    for number_section in &number_sections {
+        let part = symbol_sections.iter().any(|symbol_section| {
+            is_number_section_adjacent_to_symbol_section(number_section, symbol_section)
+        });
+        paint_number(
+            &mut grid,
+            number_section,
+            if part { PART } else { ISOLATED },
+        );
+    }
+    for symbol_section in &symbol_sections {
+        match gear_numbers(&number_sections, symbol_section) {
+            Some((number_section_1, number_section_2)) => {
+                grid.paint(symbol_section.x, symbol_section.y, GEAR);
+                paint_number(&mut grid, number_section_1, RATIO);
+                paint_number(&mut grid, number_section_2, RATIO);
+            }
+            None => grid.paint(symbol_section.x, symbol_section.y, SYMBOL),
+        }
+    }
+    grid.describe(PART, "part number");
+    grid.describe(ISOLATED, "number without a symbol");
+    grid.describe(SYMBOL, "symbol");
+    grid.describe(GEAR, "gear");
+    grid.describe(RATIO, "gear ratio number");
+    Ok(grid)
+}
+
+fn paint_number(grid: &mut Grid, number_section: &NumberSection, style: Style) {
+    for x in number_section.start_x..=number_section.end_x {
+        grid.paint(x, number_section.y, style);
+    }
+}
+
+/// Reads the digits of a number, or fails with the coordinate it starts at if it doesn't fit in
+/// a `u32`.
+fn read_number_section_from_iterator(
+    iter: &mut Peekable<impl Iterator<Item = (usize, char)>>,
+    line_length: usize,
+) -> Result<NumberSection, (usize, usize)> {
+    let mut value: u32 = 0;
+    let (start_x, y) = convert_index_to_coordinate(iter.peek().unwrap().0, line_length);
+    let mut end_x = 0;
+    while let Some((index, digit)) = iter.peek() {
+        let (x, local_y) = convert_index_to_coordinate(*index, line_length);
+        if local_y == y {
+            if let Some(digit) = digit.to_digit(10) {
+                end_x = x;
+                value = value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add(digit))
+                    .ok_or((start_x, y))?;
+                iter.next().unwrap(); // advance iterator to remove digit.
+            } else {
+                break;
+            }
+        } else {
+            break;
+        }
+    }
+    Ok(NumberSection {
+        value,
+        start_x,
+        end_x,
+        y,
+    })
+}
+
+fn read_symbol_section_from_iterator(
+    iter: &mut impl Iterator<Item = (usize, char)>,
+    line_length: usize,
+) -> SymbolSection {
+    let (index, symbol) = iter.next().unwrap(); // caller should check the next char is a symbol we care about.
+    let (x, y) = convert_index_to_coordinate(index, line_length);
+    SymbolSection { symbol, x, y }
+}
+
+fn read_sections_from_iterator(
+    iter: impl Iterator<Item = (usize, char)>,
+    line_length: usize,
+) -> Result<(Vec<NumberSection>, Vec<SymbolSection>), (usize, usize)> {
+    let mut number_sections = vec![];
+    let mut symbol_sections = vec![];
+    let mut iter = iter.peekable();
+    while let Some((_, peeked)) = iter.peek() {
+        if *peeked == '.' {
+            iter.next(); // just throw away periods
+        } else if peeked.is_ascii_digit() {
+            number_sections.push(read_number_section_from_iterator(&mut iter, line_length)?);
+        } else {
+            symbol_sections.push(read_symbol_section_from_iterator(&mut iter, line_length));
+        }
+    }
+    Ok((number_sections, symbol_sections))
+}
+
+fn read_sections_from_string(
+    string: &str,
+) -> Result<(Vec<NumberSection>, Vec<SymbolSection>), ParseError> {
+    let mut lines = string.trim().lines().map(|line| line.trim());
+    let line_length = lines
+        .next()
+        .filter(|line| !line.is_empty())
+        .ok_or_else(|| ParseError::at_end(string, "a schematic"))?
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .count();
+    for line in lines {
+        if line.chars().filter(|c| !c.is_ascii_whitespace()).count() != line_length {
+            return Err(ParseError::new(
+                string,
+                fragment_from(line, line_length),
+                "a line the same length as the first",
+            ));
+        }
+    }
+    read_sections_from_iterator(
+        string
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .enumerate(),
+        line_length,
+    )
+    .map_err(|(x, y)| {
+        let line = string.trim().lines().nth(y).unwrap().trim();
+        ParseError::new(
+            string,
+            fragment_from(line, x),
+            "a part number that fits in a u32",
+        )
+    })
+}
+
+/// The rest of `line` from its `x`th character that isn't whitespace, the column the sections
+/// are read at.
+fn fragment_from(line: &str, x: usize) -> &str {
+    line.char_indices()
+        .filter(|(_, c)| !c.is_ascii_whitespace())
+        .nth(x)
+        .map_or(&line[line.len()..], |(index, _)| &line[index..])
+}
+
+const fn convert_index_to_coordinate(index: usize, line_length: usize) -> (usize, usize) {
+    let y = index / line_length;
+    let x = index % line_length;
+    (x, y)
+}
+
+const fn is_number_section_adjacent_to_symbol_section(
+    number_section: &NumberSection,
+    symbol_section: &SymbolSection,
+) -> bool {
+    symbol_section.x >= number_section.start_x.saturating_sub(1)
+        && symbol_section.x <= number_section.end_x + 1
+        && symbol_section.y >= number_section.y.saturating_sub(1)
+        && symbol_section.y <= number_section.y + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::iter;
+
+    #[test]
+    fn test_render() {
+        const INPUT: &str = "
+        467..114..
+        ...*......
+        ..35..633.
+        ......#...
+        617*......
+        .....+.58.
+        ..592.....
+        ......755.
+        ...$.*....
+        .664.598..
+        ";
+        let output = render(INPUT).unwrap();
+        let colour = |x, y| output.get(x, y).unwrap().1.colour;
+        assert_eq!((output.width(), output.height()), (10, 10));
+        assert_eq!(
+            output.get(3, 1).unwrap(),
+            ('*', Style::colour(Colour::Yellow).bold())
+        );
+        assert_eq!(colour(0, 0), Some(Colour::Yellow));
+        assert_eq!(colour(3, 2), Some(Colour::Yellow));
+        assert_eq!(colour(5, 0), Some(Colour::Grey));
+        assert_eq!(colour(7, 2), Some(Colour::Green));
+        assert_eq!(colour(3, 4), Some(Colour::Cyan));
+        assert_eq!(colour(1, 0), Some(Colour::Yellow));
+        assert_eq!(colour(4, 0), None);
+    }
+
+    #[test]
+    fn test_read_number_section_from_iterator() {
+        let mut input = iter::once((3, '2'))
+            .chain(iter::once((4, '8')))
+            .chain(iter::once((5, '2')))
+            .chain(iter::once((6, '3')))
+            .peekable();
+        const INPUT_LINE_LENGTH: usize = 7;
+        const EXPECTED: NumberSection = NumberSection {
+            value: 2823,
+            start_x: 3,
+            end_x: 6,
+            y: 0,
+        };
+        let output = read_number_section_from_iterator(&mut input, INPUT_LINE_LENGTH);
+        assert_eq!(output, Ok(EXPECTED));
+        assert_eq!(input.next(), None);
+    }
+
+    #[test]
+    fn test_read_number_section_from_iterator_with_remaining() {
+        let mut input = iter::once((3, '2'))
+            .chain(iter::once((4, '8')))
+            .chain(iter::once((5, '2')))
+            .chain(iter::once((6, '3')))
+            .chain(iter::once((7, '*')))
+            .peekable();
+        const INPUT_LINE_LENGTH: usize = 8;
+        const EXPECTED: NumberSection = NumberSection {
+            value: 2823,
+            start_x: 3,
+            end_x: 6,
+            y: 0,
+        };
+        let output = read_number_section_from_iterator(&mut input, INPUT_LINE_LENGTH);
+        assert_eq!(output, Ok(EXPECTED));
+        assert_eq!(input.next(), Some((7, '*')));
+    }
+
+    #[test]
+    fn test_read_number_section_from_iterator_with_no_digits() {
+        let mut input = iter::once((7, '*')).peekable();
+        const INPUT_LINE_LENGTH: usize = 5;
+        const EXPECTED: NumberSection = NumberSection {
+            value: 0,
+            start_x: 2,
+            y: 1,
+            end_x: 0,
+        };
+        let output = read_number_section_from_iterator(&mut input, INPUT_LINE_LENGTH);
+        assert_eq!(output, Ok(EXPECTED));
+        assert_eq!(input.next(), Some((7, '*')));
+    }
+
+    #[test]
+    fn test_read_symbol_section_from_iterator() {
+        let mut input = iter::once((8, '#'));
+        const INPUT_LINE_LENGTH: usize = 5;
+        const EXPECTED: SymbolSection = SymbolSection {
+            symbol: '#',
+            x: 3,
+            y: 1,
+        };
+        let output = read_symbol_section_from_iterator(&mut input, INPUT_LINE_LENGTH);
+        assert_eq!(output, EXPECTED);
+        assert_eq!(input.next(), None);
+    }
+
+    #[test]
+    fn test_read_sections_from_iterator() {
+        let input = "2345..*.#".chars().enumerate();
+        let expected = (
+            vec![NumberSection {
+                value: 2345,
+                start_x: 0,
+                end_x: 3,
+                y: 0,
+            }],
+            vec![
+                SymbolSection {
+                    symbol: '*',
+                    x: 6,
+                    y: 0,
+                },
+                SymbolSection {
+                    symbol: '#',
+                    x: 8,
+                    y: 0,
+                },
+            ],
+        );
+        let output = read_sections_from_iterator(input, 9);
+        assert_eq!(output, Ok(expected));
+    }
+
+    #[test]
+    fn test_read_sections_from_string_single_line() {
+        const INPUT: &str = "2345..*.#";
+        let expected = (
+            vec![NumberSection {
+                value: 2345,
+                start_x: 0,
+                end_x: 3,
+                y: 0,
+            }],
+            vec![
+                SymbolSection {
+                    symbol: '*',
+                    x: 6,
+                    y: 0,
+                },
+                SymbolSection {
+                    symbol: '#',
+                    x: 8,
+                    y: 0,
+                },
+            ],
+        );
+        let output = read_sections_from_string(INPUT).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_read_sections_from_string_multi_line() {
+        const INPUT: &str = "
+        2345..*.#
+        ..?.1719%
+        ";
+        let expected = (
+            vec![
+                NumberSection {
+                    value: 2345,
+                    start_x: 0,
+                    end_x: 3,
+                    y: 0,
+                },
+                NumberSection {
+                    value: 1719,
+                    start_x: 4,
+                    end_x: 7,
+                    y: 1,
+                },
+            ],
+            vec![
+                SymbolSection {
+                    symbol: '*',
+                    x: 6,
+                    y: 0,
+                },
+                SymbolSection {
+                    symbol: '#',
+                    x: 8,
+                    y: 0,
+                },
+                SymbolSection {
+                    symbol: '?',
+                    x: 2,
+                    y: 1,
+                },
+                SymbolSection {
+                    symbol: '%',
+                    x: 8,
+                    y: 1,
+                },
+            ],
+        );
+        let output = read_sections_from_string(INPUT).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_read_sections_from_string_short_line() {
+        const INPUT: &str = "
+        2345..*.#
+        ..?.1719
+        ";
+        const EXPECTED: &str =
+            "line 3, column 17: expected a line the same length as the first, found end of line";
+        let output = read_sections_from_string(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_read_sections_from_string_long_line() {
+        const INPUT: &str = "
+        2345..*.#
+        ..?.1719%.
+        ";
+        const EXPECTED: &str =
+            "line 3, column 18: expected a line the same length as the first, found \".\"";
+        let output = read_sections_from_string(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_read_sections_from_string_number_too_large() {
+        const INPUT: &str = "
+        467..114.......
+        ...*...........
+        ..99999999999*1
+        ";
+        const EXPECTED: &str =
+            "line 4, column 11: expected a part number that fits in a u32, found \"99999999999*1\"";
+        let output = read_sections_from_string(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_convert_index_to_coordinate() {
+        const INPUT_INDEX: usize = 17;
+        const INPUT_LINE_LENGTH: usize = 4;
+        const EXPECTED: (usize, usize) = (1, 4);
+        let output = convert_index_to_coordinate(INPUT_INDEX, INPUT_LINE_LENGTH);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_number_section_adjacent_to_symbol_section() {
+        const INPUT_NUMBER_SECTION: &NumberSection = &NumberSection {
+            value: 1719,
+            start_x: 4,
+            end_x: 7,
+            y: 1,
+        };
+        const INPUT_SYMBOL_SECTION: &SymbolSection = &SymbolSection {
+            symbol: '%',
+            x: 8,
+            y: 1,
+        };
+        const EXPECTED: bool = true;
+        let output = is_number_section_adjacent_to_symbol_section(
+            INPUT_NUMBER_SECTION,
+            INPUT_SYMBOL_SECTION,
+        );
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_number_section_not_adjacent_to_symbol_section() {
+        const INPUT_NUMBER_SECTION: &NumberSection = &NumberSection {
+            value: 1719,
+            start_x: 4,
+            end_x: 7,
+            y: 1,
+        };
+        const INPUT_SYMBOL_SECTION: &SymbolSection = &SymbolSection {
+            symbol: '?',
+            x: 2,
+            y: 1,
+        };
+        const EXPECTED: bool = false;
+        let output = is_number_section_adjacent_to_symbol_section(
+            INPUT_NUMBER_SECTION,
+            INPUT_SYMBOL_SECTION,
+        );
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        const INPUT: &str = "
+        467..114..
+        ...*......
+        ..35..633.
+        ......#...
+        617*......
+        .....+..58
+        592.......
+        ......755.
+        ...$.*....
+        .664.598..
+        ";
+        const EXPECTED: IntegerType = 3769;
+        let (number_sections, symbol_sections) = read_sections_from_string(INPUT).unwrap();
+        let output = solve_part_1::<IntegerType>(&number_sections, &symbol_sections);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        const INPUT: &str = "
+        467..114..
+        ...*......
+        ..35..633.
+        ......#...
+        617*......
+        .....+.58.
+        ..592.....
+        ......755.
+        ...$.*....
+        .664.598..
+        ";
+        const EXPECTED: IntegerType = 467835;
+        let (number_sections, symbol_sections) = read_sections_from_string(INPUT).unwrap();
+        let output = solve_part_2::<IntegerType>(&number_sections, &symbol_sections);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_widens_on_overflow() {
+        // the part numbers add up to 5000000000 and multiply to 4000000000000000000, both past
+        // the largest u32, while each of them fits.
+        const INPUT: &str = "4000000000*1000000000";
+        let parsed = read_sections_from_string(INPUT).unwrap();
+        assert_eq!(Day03::solve_part_1(&parsed), Ok(Widened::Wide(5000000000)));
+        assert_eq!(
+            Day03::solve_part_2(&parsed),
+            Ok(Widened::Wide(4000000000000000000))
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use alloc::collections::VecDeque;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::num::{self, Integer, Overflow, Widened};
+use crate::parallel;
+use crate::stream::Streaming;
+use crate::trace;
+use crate::{ParseError, Part, Solution, SolveError};
+
+type IntegerType = u32;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+    type Parsed<'a> = Vec<usize>;
+    type Part1 = Widened<IntegerType>;
+    type Part2 = Widened<IntegerType>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_matching_counts(input).map_err(|error| error.on_day(Self::DAY))
+    }
+
+    fn solve_part_1(counts: &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(num::widen(
+            || solve_part_1(counts),
+            || solve_part_1(counts),
+        )?)
+    }
+
+    fn solve_part_2(counts: &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(num::widen(
+            || solve_part_2(counts),
+            || solve_part_2(counts),
+        )?)
+    }
+}
+
+impl Streaming for Day04 {
+    type Line<'a> = usize;
+    type State = Pile;
+
+    fn parse_line(line: &str) -> Result<Self::Line<'_>, ParseError> {
+        count_matching_numbers_in_game(line)
+    }
+
+    fn fold_line(pile: &mut Self::State, count: Self::Line<'_>) -> Result<(), Overflow> {
+        pile.add_card(count)
+    }
+
+    fn finish(pile: Self::State) -> (Self::Part1, Self::Part2) {
+        (pile.points, pile.cards)
+    }
+}
+
+/// The running totals while cards arrive one at a time. Copies only ever go to the next few
+/// cards, so only those copies need to be kept, at most as many as a card has numbers.
+#[derive(Default)]
+pub struct Pile {
+    points: Widened<IntegerType>,
+    cards: Widened<IntegerType>,
+    pending: VecDeque<Widened<IntegerType>>,
+}
+
+impl Pile {
+    fn add_card(&mut self, count: usize) -> Result<(), Overflow> {
+        let points = num::widen(
+            || score_game_for_part_1(count),
+            || score_game_for_part_1(count),
+        );
+        self.points = points
+            .and_then(|points| self.points.plus(points))
+            .map_err(|overflow| overflow.in_part(Part::One))?;
+        self.add_copies(count)
+            .map_err(|overflow| overflow.in_part(Part::Two))
+    }
+
+    fn add_copies(&mut self, count: usize) -> Result<(), Overflow> {
+        let copies = self
+            .pending
+            .pop_front()
+            .unwrap_or_default()
+            .plus(Widened::Narrow(1))?;
+        self.cards = self.cards.plus(copies)?;
+        for i in 0..count {
+            match self.pending.get_mut(i) {
+                Some(pending) => *pending = pending.plus(copies)?,
+                None => self.pending.push_back(copies),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn count_matching_numbers_in_game(string: &str) -> Result<usize, ParseError> {
+    let (card, numbers) = string
+        .split_once(": ")
+        .ok_or_else(|| ParseError::at_end(string, "\": \" after the card number"))?;
+    let (winning_numbers, your_numbers) = numbers
+        .split_once(" | ")
+        .ok_or_else(|| ParseError::at_end(string, "\" | \" between the numbers"))?;
+    let winning_numbers = parse_numbers(string, winning_numbers)?;
+    let your_numbers = parse_numbers(string, your_numbers)?;
+    let matched = winning_numbers
+        .iter()
+        .filter(|winning_number| your_numbers.contains(winning_number));
+    if trace::enabled() {
+        let numbers: Vec<_> = matched.clone().map(u32::to_string).collect();
+        let card = card.trim_start_matches("Card").trim();
+        trace::event("card", &[("card", &card), ("matched", &numbers.join(" "))]);
+    }
+    Ok(matched.count())
+}
+
+fn parse_numbers(string: &str, numbers: &str) -> Result<Vec<u32>, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|number| {
+            number
+                .parse()
+                .map_err(|_| ParseError::new(string, number, "a number"))
+        })
+        .collect()
+}
+
+fn parse_matching_counts(string: &str) -> Result<Vec<usize>, ParseError> {
+    let lines: Vec<_> = string.trim().lines().map(str::trim).collect();
+    parallel::map(&lines, |line| {
+        count_matching_numbers_in_game(line).map_err(|error| error.within(string, line))
+    })
+    .into_iter()
+    .collect()
+}
+
+fn score_game_for_part_1<N: Integer>(count: usize) -> Result<N, Overflow> {
+    if count == 0 {
+        Ok(N::ZERO)
+    } else {
+        (1..count).try_fold(N::ONE, |score, _| score.try_mul(N::from(2)))
+    }
+}
+
+fn solve_part_1<N: Integer>(counts: &[usize]) -> Result<N, Overflow> {
+    counts.iter().try_fold(N::ZERO, |total, count| {
+        total.try_add(score_game_for_part_1(*count)?)
+    })
+}
+
+/// Counts the cards, where copies of cards past the end of the table are never won, as the
+/// streaming [`Pile`] can't know where the end is until it gets there.
+fn solve_part_2<N: Integer>(counts: &[usize]) -> Result<N, Overflow> {
+    let mut dp = vec![N::ZERO; counts.len()];
+    for (index, count) in counts.iter().copied().enumerate().rev() {
+        dp[index] = num::sum(dp[index + 1..].iter().copied().take(count))?.try_add(N::ONE)?;
+    }
+    num::sum(dp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream;
+
+    #[test]
+    fn test_pile() {
+        const INPUT: &[usize] = &[4, 2, 2, 1, 0, 0];
+        const EXPECTED: (Widened<IntegerType>, Widened<IntegerType>) =
+            (Widened::Narrow(13), Widened::Narrow(30));
+        let mut pile = Pile::default();
+        for count in INPUT {
+            pile.add_card(*count).unwrap();
+            assert!(pile.pending.len() <= 4);
+        }
+        assert_eq!((pile.points, pile.cards), EXPECTED);
+    }
+
+    #[test]
+    fn test_count_matching_numbers_in_game() {
+        const INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        const EXPECTED: usize = 4;
+        let output = count_matching_numbers_in_game(INPUT).unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_count_matching_numbers_in_game_trace() {
+        const INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        const EXPECTED: &str = "card card=1 matched=\"48 83 86 17\"";
+        let (output, events) = trace::collect(|| count_matching_numbers_in_game(INPUT));
+        assert_eq!(output.unwrap(), 4);
+        let events: Vec<_> = events.iter().map(ToString::to_string).collect();
+        assert_eq!(events, [EXPECTED]);
+    }
+
+    #[test]
+    fn test_parse_matching_counts_missing_separator() {
+        const INPUT: &str = "
+        Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61   61 30 68 82 17 32 24 19
+        ";
+        const EXPECTED: &str =
+            "line 3, column 57: expected \" | \" between the numbers, found end of line";
+        let output = parse_matching_counts(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_score_game_for_part_1() {
+        const INPUT: usize = 4;
+        const EXPECTED: IntegerType = 8;
+        let output = score_game_for_part_1(INPUT);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        const INPUT: &str = "
+        Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+        ";
+        const EXPECTED: IntegerType = 13;
+        let output = solve_part_1::<IntegerType>(&parse_matching_counts(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        const INPUT: &str = "
+        Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+        ";
+        const EXPECTED: IntegerType = 30;
+        let output = solve_part_2::<IntegerType>(&parse_matching_counts(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_copies_past_the_end() {
+        const INPUT: &str = "
+        Card 1:  1  2  3 | 1 2 3
+        Card 2:  4  5  6 | 4 5 7
+        ";
+        const EXPECTED: (Widened<IntegerType>, Widened<IntegerType>) =
+            (Widened::Narrow(6), Widened::Narrow(3));
+        let parsed = Day04::parse(INPUT).unwrap();
+        let output = (Day04::solve_part_1(&parsed), Day04::solve_part_2(&parsed));
+        assert_eq!(output, (Ok(EXPECTED.0), Ok(EXPECTED.1)));
+        let output = stream::solve::<Day04>(INPUT.as_bytes()).unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_solve_widens_on_overflow() {
+        // each card matches one fewer number than the last, from 32 down to none. Their points
+        // add up to 4294967295, the largest u32, while the 8589934591 cards they win don't fit.
+        const EXPECTED: (Widened<IntegerType>, Widened<IntegerType>) =
+            (Widened::Narrow(4294967295), Widened::Wide(8589934591));
+        let counts: Vec<usize> = (0..=32).rev().collect();
+        let output = (Day04::solve_part_1(&counts), Day04::solve_part_2(&counts));
+        assert_eq!(output, (Ok(EXPECTED.0), Ok(EXPECTED.1)));
+        let mut pile = Pile::default();
+        for count in counts {
+            pile.add_card(count).unwrap();
+        }
+        assert_eq!((pile.points, pile.cards), EXPECTED);
+    }
+}
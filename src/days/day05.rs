@@ -0,0 +1,864 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::num::{self, Integer, Overflow, Widened};
+use crate::trace;
+use crate::{ParseError, Solution, SolveError};
+
+type IntegerType = u32;
+type Wide = <IntegerType as Integer>::Wide;
+
+#[derive(Debug, PartialEq)]
+enum RelationType {
+    Before,
+    After,
+    OverlapStart,
+    OverlapEnd,
+    Contained,
+    Envelops,
+}
+
+#[derive(Debug, PartialEq)]
+enum MappingResult<N> {
+    Nothing,
+    Unmapped((N, N)),
+    Mapped((N, N)),
+}
+
+impl<N: fmt::Display> fmt::Display for MappingResult<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MappingResult::Nothing => write!(f, "nothing"),
+            MappingResult::Unmapped((start, length)) => write!(f, "unmapped {start}+{length}"),
+            MappingResult::Mapped((start, length)) => write!(f, "mapped {start}+{length}"),
+        }
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+    type Parsed<'a> = (Vec<Wide>, Vec<Map>);
+    type Part1 = Widened<IntegerType>;
+    type Part2 = Widened<IntegerType>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_seed_numbers_and_maps_from_string(input).map_err(|error| error.on_day(Self::DAY))
+    }
+
+    fn solve_part_1((numbers, maps): &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(num::widen(
+            || {
+                let (numbers, maps) = narrow(numbers, maps)?;
+                solve_part_1(&numbers, &maps)
+            },
+            || solve_part_1(numbers, maps),
+        )?)
+    }
+
+    fn solve_part_2((numbers, maps): &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(num::widen(
+            || {
+                let (numbers, maps) = narrow(numbers, maps)?;
+                solve_part_2(&numbers, &maps)
+            },
+            || solve_part_2(numbers, maps),
+        )?)
+    }
+}
+
+/// Maps every seed on its own instead of splitting ranges, in the wide type. It is far too slow
+/// for the real input, but simple enough to check [`Day05`] against on small ones.
+pub struct Day05PerSeed;
+
+impl Solution for Day05PerSeed {
+    const DAY: u8 = Day05::DAY;
+    const TITLE: &'static str = Day05::TITLE;
+    type Parsed<'a> = (Vec<Wide>, Vec<Map>);
+    type Part1 = Wide;
+    type Part2 = Wide;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Day05::parse(input)
+    }
+
+    fn solve_part_1((numbers, maps): &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(numbers
+            .iter()
+            .map(|number| map_seed(*number, maps))
+            .min()
+            .unwrap())
+    }
+
+    fn solve_part_2((numbers, maps): &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(numbers
+            .chunks(2)
+            .filter(|chunk| chunk[1] > 0)
+            .flat_map(|chunk| chunk[0]..=chunk[0] + (chunk[1] - 1))
+            .map(|number| map_seed(number, maps))
+            .min()
+            .unwrap())
+    }
+}
+
+/// Converts the parsed seeds and maps to `N`, if every number fits in it.
+fn narrow<N: Integer<Wide = Wide>>(
+    numbers: &[Wide],
+    maps: &[Map],
+) -> Result<(Vec<N>, Vec<Map<N>>), Overflow> {
+    let maps = maps
+        .iter()
+        .map(|map| {
+            map.iter()
+                .map(|(destination_start, source_start, map_length)| {
+                    Ok((
+                        N::narrow(*destination_start)?,
+                        N::narrow(*source_start)?,
+                        N::narrow(*map_length)?,
+                    ))
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((num::narrow_all(numbers)?, maps))
+}
+
+fn solve_part_1<N: Integer>(
+    numbers: &[N],
+    maps: &[impl AsRef<[Section<N>]>],
+) -> Result<N, Overflow> {
+    let mut ranges = numbers.iter().map(|num| (*num, N::ONE)).collect();
+    solve_for_ranges(&mut ranges, maps)
+}
+
+fn solve_part_2<N: Integer>(
+    numbers: &[N],
+    maps: &[impl AsRef<[Section<N>]>],
+) -> Result<N, Overflow> {
+    let mut ranges = numbers
+        .chunks(2)
+        .map(|chunk| (chunk[0], chunk[1]))
+        .collect();
+    solve_for_ranges(&mut ranges, maps)
+}
+
+fn solve_for_ranges<N: Integer>(
+    ranges: &mut Vec<(N, N)>,
+    maps: &[impl AsRef<[Section<N>]>],
+) -> Result<N, Overflow> {
+    fully_map_ranges(ranges, maps)?;
+    Ok(ranges.iter().map(|(start, _)| *start).min().unwrap())
+}
+
+fn fully_map_ranges<N: Integer>(
+    ranges: &mut Vec<(N, N)>,
+    maps: &[impl AsRef<[Section<N>]>],
+) -> Result<(), Overflow> {
+    let mut mapped: Vec<(N, N)> = Vec::new();
+    let mut unmapped: Vec<(N, N)> = Vec::new();
+    for (index, map) in maps.iter().enumerate() {
+        let _scope = trace::scope("map", &[("map", &(index + 1)), ("ranges", &ranges.len())]);
+        for section in map.as_ref() {
+            while let Some(range) = ranges.pop() {
+                if range.1 == N::ZERO {
+                    continue; // splitting can leave empty ranges which have no end.
+                }
+                let (start, middle, end) = map_range(range, *section)?;
+                match start {
+                    MappingResult::Mapped(range) => mapped.push(range),
+                    MappingResult::Unmapped(range) => unmapped.push(range),
+                    _ => {}
+                };
+                match middle {
+                    MappingResult::Mapped(range) => mapped.push(range),
+                    MappingResult::Unmapped(range) => unmapped.push(range),
+                    _ => {}
+                };
+                match end {
+                    MappingResult::Mapped(range) => mapped.push(range),
+                    MappingResult::Unmapped(range) => unmapped.push(range),
+                    _ => {}
+                };
+            }
+            core::mem::swap(ranges, &mut unmapped);
+        }
+        ranges.append(&mut mapped);
+    }
+    Ok(())
+}
+
+/// Parses the seeds, which are pairs of a start and a length above zero, as they are read as
+/// ranges for part 2.
+fn parse_numbers_from_string(string: &str) -> Result<Vec<Wide>, ParseError> {
+    let words: Vec<_> = string
+        .split_once(": ")
+        .ok_or_else(|| ParseError::at_end(string, "\": \" after \"seeds\""))?
+        .1
+        .split_whitespace()
+        .collect();
+    if words.is_empty() {
+        return Err(ParseError::at_end(string, "a seed"));
+    }
+    if words.len() % 2 == 1 {
+        return Err(ParseError::at_end(string, "a length after the last seed"));
+    }
+    let numbers = words
+        .iter()
+        .map(|number| parse_number(string, number))
+        .collect::<Result<Vec<_>, _>>()?;
+    match numbers.chunks(2).position(|pair| pair[1] == 0) {
+        Some(index) => Err(ParseError::new(
+            string,
+            words[index * 2 + 1],
+            "a length above zero",
+        )),
+        None => Ok(numbers),
+    }
+}
+
+fn parse_map_from_string(string: &str) -> Result<Map, ParseError> {
+    string
+        .lines()
+        .skip(1)
+        .map(|line| parse_line(line).map_err(|error| error.within(string, line)))
+        .collect()
+}
+
+fn parse_line(string: &str) -> Result<Section, ParseError> {
+    let mut iter = string.split_whitespace();
+    let mut next = || match iter.next() {
+        Some(number) => parse_number(string, number),
+        None => Err(ParseError::at_end(string, "three numbers")),
+    };
+    let line = (next()?, next()?, next()?);
+    if let Some(extra) = iter.next() {
+        return Err(ParseError::new(string, extra, "three numbers"));
+    }
+    match line.2 {
+        0 => Err(ParseError::new(
+            string,
+            string.split_whitespace().nth(2).unwrap(),
+            "a length above zero",
+        )),
+        _ => Ok(line),
+    }
+}
+
+fn parse_number(string: &str, number: &str) -> Result<Wide, ParseError> {
+    number
+        .parse()
+        .map_err(|_| ParseError::new(string, number, "a number"))
+}
+
+/// The destination start, source start and length of a section of a map, parsed in the wide
+/// type so that solving can fall back to it.
+type Section<N = Wide> = (N, N, N);
+type Map<N = Wide> = Vec<Section<N>>;
+
+fn map_seed(seed: Wide, maps: &[Map]) -> Wide {
+    maps.iter().fold(seed, |number, map| {
+        map.iter()
+            .find(|(_, source_start, map_length)| {
+                number >= *source_start && number - source_start < *map_length
+            })
+            .map_or(number, |(destination_start, source_start, _)| {
+                destination_start + (number - source_start)
+            })
+    })
+}
+
+fn parse_seed_numbers_and_maps_from_string(
+    string: &str,
+) -> Result<(Vec<Wide>, Vec<Map>), ParseError> {
+    let mut chunks = string.trim().split("\n\n").map(|line| line.trim());
+    let seeds = chunks.next().unwrap_or_default();
+    let seed_numbers =
+        parse_numbers_from_string(seeds).map_err(|error| error.within(string, seeds))?;
+    let maps = chunks
+        .map(|chunk| parse_map_from_string(chunk).map_err(|error| error.within(string, chunk)))
+        .collect::<Result<_, _>>()?;
+    Ok((seed_numbers, maps))
+}
+
+fn relation_type<N: Integer>(
+    (range_start, range_length): (N, N),
+    (_, source_start, map_length): (N, N, N),
+) -> Result<RelationType, Overflow> {
+    let range_end = range_start.try_add(range_length.try_sub(N::ONE)?)?;
+    let source_end = source_start.try_add(map_length.try_sub(N::ONE)?)?;
+    Ok(if range_end < source_start {
+        RelationType::Before
+    } else if range_start > source_end {
+        RelationType::After
+    } else if range_start < source_start && range_end <= source_end {
+        RelationType::OverlapStart
+    } else if range_start >= source_start && range_end > source_end {
+        RelationType::OverlapEnd
+    } else if range_start <= source_start && range_end >= source_end {
+        RelationType::Envelops
+    } else {
+        RelationType::Contained
+    })
+}
+
+type MappingResults<N> = (MappingResult<N>, MappingResult<N>, MappingResult<N>);
+
+fn map_range<N: Integer>(
+    (range_start, range_length): (N, N),
+    (destination_start, source_start, map_length): (N, N, N),
+) -> Result<MappingResults<N>, Overflow> {
+    let relation = relation_type(
+        (range_start, range_length),
+        (destination_start, source_start, map_length),
+    )?;
+    let results = match relation {
+        RelationType::Before => Ok(map_range_with_before_relation(
+            (range_start, range_length),
+            (destination_start, source_start, map_length),
+        )),
+        RelationType::After => Ok(map_range_with_after_relation(
+            (range_start, range_length),
+            (destination_start, source_start, map_length),
+        )),
+        RelationType::OverlapStart => map_range_with_overlap_start_relation(
+            (range_start, range_length),
+            (destination_start, source_start, map_length),
+        ),
+        RelationType::OverlapEnd => map_range_with_overlap_end_relation(
+            (range_start, range_length),
+            (destination_start, source_start, map_length),
+        ),
+        RelationType::Contained => map_range_with_contained_relation(
+            (range_start, range_length),
+            (destination_start, source_start, map_length),
+        ),
+        RelationType::Envelops => map_range_with_envelops_relation(
+            (range_start, range_length),
+            (destination_start, source_start, map_length),
+        ),
+    }?;
+    if trace::enabled() {
+        let pieces: Vec<_> = [&results.0, &results.1, &results.2]
+            .into_iter()
+            .filter(|result| **result != MappingResult::Nothing)
+            .map(ToString::to_string)
+            .collect();
+        trace::event(
+            "split",
+            &[
+                ("range", &format_args!("{range_start}+{range_length}")),
+                (
+                    "section",
+                    &format_args!("{source_start}+{map_length} to {destination_start}"),
+                ),
+                ("relation", &format_args!("{relation:?}")),
+                ("into", &pieces.join(", ")),
+            ],
+        );
+    }
+    Ok(results)
+}
+
+fn map_range_with_before_relation<N>(
+    (range_start, range_length): (N, N),
+    (_, _, _): (N, N, N),
+) -> MappingResults<N> {
+    (
+        MappingResult::Unmapped((range_start, range_length)),
+        MappingResult::Nothing,
+        MappingResult::Nothing,
+    )
+}
+
+fn map_range_with_after_relation<N>(
+    (range_start, range_length): (N, N),
+    (_, _, _): (N, N, N),
+) -> MappingResults<N> {
+    (
+        MappingResult::Nothing,
+        MappingResult::Nothing,
+        MappingResult::Unmapped((range_start, range_length)),
+    )
+}
+
+fn map_range_with_overlap_start_relation<N: Integer>(
+    (range_start, range_length): (N, N),
+    (destination_start, source_start, _): (N, N, N),
+) -> Result<MappingResults<N>, Overflow> {
+    let number_unmapped = source_start.try_sub(range_start)?;
+    let number_mapped = range_length.try_sub(number_unmapped)?;
+    Ok((
+        MappingResult::Unmapped((range_start, number_unmapped)),
+        MappingResult::Mapped((destination_start, number_mapped)),
+        MappingResult::Nothing,
+    ))
+}
+
+fn map_range_with_overlap_end_relation<N: Integer>(
+    (range_start, range_length): (N, N),
+    (destination_start, source_start, map_length): (N, N, N),
+) -> Result<MappingResults<N>, Overflow> {
+    let offset = range_start.try_sub(source_start)?;
+    let number_mapped = map_length.try_sub(offset)?;
+    let number_unmapped = range_length.try_sub(number_mapped)?;
+    Ok((
+        MappingResult::Nothing,
+        MappingResult::Mapped((destination_start.try_add(offset)?, number_mapped)),
+        MappingResult::Unmapped((range_start.try_add(number_mapped)?, number_unmapped)),
+    ))
+}
+
+fn map_range_with_contained_relation<N: Integer>(
+    (range_start, range_length): (N, N),
+    (destination_start, source_start, _): (N, N, N),
+) -> Result<MappingResults<N>, Overflow> {
+    let offset = range_start.try_sub(source_start)?;
+    Ok((
+        MappingResult::Nothing,
+        MappingResult::Mapped((destination_start.try_add(offset)?, range_length)),
+        MappingResult::Nothing,
+    ))
+}
+
+fn map_range_with_envelops_relation<N: Integer>(
+    (range_start, range_length): (N, N),
+    (destination_start, source_start, map_length): (N, N, N),
+) -> Result<MappingResults<N>, Overflow> {
+    let number_preceding = source_start.try_sub(range_start)?;
+    let number_trailing = range_length
+        .try_sub(map_length)?
+        .try_sub(number_preceding)?;
+    let trailing_start = range_start.try_add(number_preceding)?.try_add(map_length)?;
+    Ok((
+        MappingResult::Unmapped((range_start, number_preceding)),
+        MappingResult::Mapped((destination_start, map_length)),
+        MappingResult::Unmapped((trailing_start, number_trailing)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_numbers_from_string() {
+        const INPUT: &str = "seeds: 79 14 55 13";
+        const EXPECTED: &[Wide] = &[79, 14, 55, 13];
+        let output = parse_numbers_from_string(INPUT).unwrap();
+        assert_eq!(&output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_numbers_from_string_invalid_seeds() {
+        const INPUT: &[&str] = &["seeds: 79 14 55", "seeds: 79 14 55 0", "seeds: "];
+        const EXPECTED: &[&str] = &[
+            "line 1, column 16: expected a length after the last seed, found end of line",
+            "line 1, column 17: expected a length above zero, found \"0\"",
+            "line 1, column 8: expected a seed, found end of line",
+        ];
+        let output: Vec<_> = INPUT
+            .iter()
+            .map(|input| parse_numbers_from_string(input).unwrap_err().to_string())
+            .collect();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        const INPUT: &str = "0 15 37";
+        const EXPECTED: (Wide, Wide, Wide) = (0, 15, 37);
+        let output = parse_line(INPUT).unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_line_zero_length() {
+        const INPUT: &str = "5 1 0";
+        const EXPECTED: &str = "line 1, column 5: expected a length above zero, found \"0\"";
+        let output = parse_line(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_map_from_string() {
+        const INPUT: &str = "
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        ";
+        const EXPECTED: &[(Wide, Wide, Wide)] = &[(0, 15, 37), (37, 52, 2), (39, 0, 15)];
+        let output = parse_map_from_string(INPUT.trim()).unwrap();
+        assert_eq!(&output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_seed_numbers_and_maps_from_string() {
+        const INPUT: &str = "
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+
+        water-to-light map:
+        88 18 7
+        18 25 70
+
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+
+        humidity-to-location map:
+        60 56 37
+        56 93 4
+        ";
+        let expected: (Vec<Wide>, Vec<Map>) = (
+            vec![79, 14, 55, 13],
+            vec![
+                vec![(50, 98, 2), (52, 50, 48)],
+                vec![(0, 15, 37), (37, 52, 2), (39, 0, 15)],
+                vec![(49, 53, 8), (0, 11, 42), (42, 0, 7), (57, 7, 4)],
+                vec![(88, 18, 7), (18, 25, 70)],
+                vec![(45, 77, 23), (81, 45, 19), (68, 64, 13)],
+                vec![(0, 69, 1), (1, 0, 69)],
+                vec![(60, 56, 37), (56, 93, 4)],
+            ],
+        );
+        let output = parse_seed_numbers_and_maps_from_string(INPUT).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_parse_line_missing_number() {
+        const INPUT: &str = "0 15";
+        const EXPECTED: &str = "line 1, column 5: expected three numbers, found end of line";
+        let output = parse_line(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_seed_numbers_and_maps_from_string_invalid_number() {
+        const INPUT: &str = "
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 5O 48
+        ";
+        const EXPECTED: &str = "line 6, column 12: expected a number, found \"5O\"";
+        let output = parse_seed_numbers_and_maps_from_string(INPUT)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        const INPUT_MAPS: &[&[(u32, u32, u32)]] = &[
+            &[(50, 98, 2), (52, 50, 48)],
+            &[(0, 15, 37), (37, 52, 2), (39, 0, 15)],
+            &[(49, 53, 8), (0, 11, 42), (42, 0, 7), (57, 7, 4)],
+            &[(88, 18, 7), (18, 25, 70)],
+            &[(45, 77, 23), (81, 45, 19), (68, 64, 13)],
+            &[(0, 69, 1), (1, 0, 69)],
+            &[(60, 56, 37), (56, 93, 4)],
+        ];
+        const INPUT_NUMBERS: &[u32] = &[79, 14, 55, 13];
+        const EXPECTED: u32 = 35;
+        let output = solve_part_1(INPUT_NUMBERS, INPUT_MAPS);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        const INPUT_MAPS: &[&[(u32, u32, u32)]] = &[
+            &[(50, 98, 2), (52, 50, 48)],
+            &[(0, 15, 37), (37, 52, 2), (39, 0, 15)],
+            &[(49, 53, 8), (0, 11, 42), (42, 0, 7), (57, 7, 4)],
+            &[(88, 18, 7), (18, 25, 70)],
+            &[(45, 77, 23), (81, 45, 19), (68, 64, 13)],
+            &[(0, 69, 1), (1, 0, 69)],
+            &[(60, 56, 37), (56, 93, 4)],
+        ];
+        const INPUT_NUMBERS: &[u32] = &[79, 14, 55, 13];
+        const EXPECTED: u32 = 46;
+        let output = solve_part_2(INPUT_NUMBERS, INPUT_MAPS);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_for_ranges() {
+        const INPUT_MAPS: &[&[(u32, u32, u32)]] = &[
+            &[(50, 98, 2), (52, 50, 48)],
+            &[(0, 15, 37), (37, 52, 2), (39, 0, 15)],
+            &[(49, 53, 8), (0, 11, 42), (42, 0, 7), (57, 7, 4)],
+            &[(88, 18, 7), (18, 25, 70)],
+            &[(45, 77, 23), (81, 45, 19), (68, 64, 13)],
+            &[(0, 69, 1), (1, 0, 69)],
+            &[(60, 56, 37), (56, 93, 4)],
+        ];
+        let mut input_ranges = vec![(79, 14), (55, 13)];
+        const EXPECTED: u32 = 46;
+        let output = solve_for_ranges(&mut input_ranges, INPUT_MAPS);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_map_range_before() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (15, 4);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Unmapped((15, 4)),
+            MappingResult::Nothing,
+            MappingResult::Nothing,
+        );
+        let output = map_range(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_map_range_after() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (27, 3);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Nothing,
+            MappingResult::Nothing,
+            MappingResult::Unmapped((27, 3)),
+        );
+        let output = map_range(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_map_range_overlap_start() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (15, 7);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Unmapped((15, 5)),
+            MappingResult::Mapped((10, 2)),
+            MappingResult::Nothing,
+        );
+        let output = map_range(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_map_range_overlap_end() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (22, 5);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Nothing,
+            MappingResult::Mapped((12, 3)),
+            MappingResult::Unmapped((25, 2)),
+        );
+        let output = map_range(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_map_range_trace() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (15, 7);
+        const EXPECTED: &str = "split range=15+7 section=\"20+5 to 10\" relation=OverlapStart \
+            into=\"unmapped 15+5, mapped 10+2\"";
+        let (_, events) = trace::collect(|| map_range(INPUT_RANGE, INPUT_MAP));
+        let events: Vec<_> = events.iter().map(ToString::to_string).collect();
+        assert_eq!(events, [EXPECTED]);
+    }
+
+    #[test]
+    fn test_map_range_contained() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (20, 4);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Nothing,
+            MappingResult::Mapped((10, 4)),
+            MappingResult::Nothing,
+        );
+        let output = map_range(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_map_range_envelops() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (15, 15);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Unmapped((15, 5)),
+            MappingResult::Mapped((10, 5)),
+            MappingResult::Unmapped((25, 5)),
+        );
+        let output = map_range(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_map_range_with_before_relation() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (15, 4);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Unmapped((15, 4)),
+            MappingResult::Nothing,
+            MappingResult::Nothing,
+        );
+        let output = map_range_with_before_relation(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_map_range_with_after_relation() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (27, 3);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Nothing,
+            MappingResult::Nothing,
+            MappingResult::Unmapped((27, 3)),
+        );
+        let output = map_range_with_after_relation(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_map_range_with_overlap_start_relation() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (15, 7);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Unmapped((15, 5)),
+            MappingResult::Mapped((10, 2)),
+            MappingResult::Nothing,
+        );
+        let output = map_range_with_overlap_start_relation(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_map_range_with_overlap_end_relation() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (22, 5);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Nothing,
+            MappingResult::Mapped((12, 3)),
+            MappingResult::Unmapped((25, 2)),
+        );
+        let output = map_range_with_overlap_end_relation(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_map_range_with_contained_relation() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (20, 4);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Nothing,
+            MappingResult::Mapped((10, 4)),
+            MappingResult::Nothing,
+        );
+        let output = map_range_with_contained_relation(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_map_range_with_envelops_relation() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (15, 15);
+        const EXPECTED: MappingResults<u32> = (
+            MappingResult::Unmapped((15, 5)),
+            MappingResult::Mapped((10, 5)),
+            MappingResult::Unmapped((25, 5)),
+        );
+        let output = map_range_with_envelops_relation(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_relation_type_before() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (15, 5);
+        const EXPECTED: RelationType = RelationType::Before;
+        let output = relation_type(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_relation_type_after() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (26, 3);
+        const EXPECTED: RelationType = RelationType::After;
+        let output = relation_type(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_relation_type_overlap_start() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (15, 7);
+        const EXPECTED: RelationType = RelationType::OverlapStart;
+        let output = relation_type(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_relation_type_overlap_end() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (22, 7);
+        const EXPECTED: RelationType = RelationType::OverlapEnd;
+        let output = relation_type(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_relation_type_contained() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (21, 3);
+        const EXPECTED: RelationType = RelationType::Contained;
+        let output = relation_type(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_relation_type_envelops() {
+        const INPUT_MAP: (u32, u32, u32) = (10, 20, 5);
+        const INPUT_RANGE: (u32, u32) = (15, 15);
+        const EXPECTED: RelationType = RelationType::Envelops;
+        let output = relation_type(INPUT_RANGE, INPUT_MAP);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_widens_on_overflow() {
+        // the seed doesn't fit in a u32, and nor does the end of the range it's mapped to.
+        const INPUT: &str = "
+        seeds: 5000000000 5
+
+        seed-to-soil map:
+        4294967295 5000000000 5
+
+        soil-to-fertilizer map:
+        0 0 1
+        ";
+        let parsed = Day05::parse(INPUT).unwrap();
+        assert_eq!(Day05::solve_part_1(&parsed), Ok(Widened::Wide(5)));
+        assert_eq!(Day05::solve_part_2(&parsed), Ok(Widened::Wide(4294967295)));
+        assert_eq!(Day05PerSeed::solve_part_2(&parsed), Ok(4294967295));
+    }
+}
@@ -0,0 +1,470 @@
+use crate::num::{self, Integer, Overflow, Widened};
+use crate::{ParseError, Solution, SolveError};
+use alloc::vec::Vec;
+
+type IntegerType = u64;
+type Wide = <IntegerType as Integer>::Wide;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+    type Parsed<'a> = Races;
+    type Part1 = Widened<IntegerType>;
+    type Part2 = Widened<IntegerType>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_races(input).map_err(|error| error.on_day(Self::DAY))
+    }
+
+    fn solve_part_1(races: &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(num::widen(|| solve_part_1(races), || solve_part_1(races))?)
+    }
+
+    fn solve_part_2(races: &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(num::widen(|| solve_part_2(races), || solve_part_2(races))?)
+    }
+}
+
+/// Counts the ways to win from where the distance crosses the record instead of trying every
+/// time, to check [`Day06`] against.
+pub struct Day06ClosedForm;
+
+impl Solution for Day06ClosedForm {
+    const DAY: u8 = Day06::DAY;
+    const TITLE: &'static str = Day06::TITLE;
+    type Parsed<'a> = Races;
+    type Part1 = Widened<IntegerType>;
+    type Part2 = Widened<IntegerType>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Day06::parse(input)
+    }
+
+    fn solve_part_1(races: &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+        fn solve<N: Integer<Wide = Wide>>(races: &Races) -> Result<N, Overflow> {
+            let limits = num::narrow_all::<N>(&races.limits)?;
+            let records = num::narrow_all(&races.records)?;
+            limits
+                .iter()
+                .zip(&records)
+                .try_fold(N::ONE, |product, (limit, record)| {
+                    product.try_mul(calculate_number_of_ways_to_win_closed_form(
+                        *limit, *record,
+                    )?)
+                })
+        }
+        Ok(num::widen(|| solve(races), || solve(races))?)
+    }
+
+    fn solve_part_2(races: &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+        fn solve<N: Integer<Wide = Wide>>(races: &Races) -> Result<N, Overflow> {
+            let (limit, record) = races.kerning_corrected;
+            calculate_number_of_ways_to_win_closed_form(N::narrow(limit)?, N::narrow(record)?)
+        }
+        Ok(num::widen(|| solve(races), || solve(races))?)
+    }
+}
+
+/// The races, parsed in the wide type so that solving can fall back to it.
+#[derive(Debug, PartialEq)]
+pub struct Races {
+    limits: Vec<Wide>,
+    records: Vec<Wide>,
+    kerning_corrected: (Wide, Wide),
+}
+
+fn parse_races(input: &str) -> Result<Races, ParseError> {
+    let (limits, records) = parse_times_and_distances_from_string(input)?;
+    let kerning_corrected = parse_time_and_distance_from_string_with_kerning_correction(input)?;
+    Ok(Races {
+        limits,
+        records,
+        kerning_corrected,
+    })
+}
+
+fn parse_times_and_distances_from_string<N: Integer>(
+    input: &str,
+) -> Result<(Vec<N>, Vec<N>), ParseError> {
+    let (times, distances) = split_times_and_distances(input)?;
+    let times: Vec<&str> = times.split_whitespace().collect();
+    let distances: Vec<&str> = distances.split_whitespace().collect();
+    if let Some(time) = times.get(distances.len()) {
+        return Err(ParseError::new(input, time, "a distance for every time"));
+    }
+    if let Some(distance) = distances.get(times.len()) {
+        return Err(ParseError::new(
+            input,
+            distance,
+            "a time for every distance",
+        ));
+    }
+    let parse_numbers = |numbers: &[&str]| -> Result<Vec<N>, ParseError> {
+        numbers
+            .iter()
+            .map(|num| {
+                num.parse()
+                    .map_err(|_| ParseError::new(input, num, "a number"))
+            })
+            .collect()
+    };
+    Ok((parse_numbers(&times)?, parse_numbers(&distances)?))
+}
+
+fn parse_time_and_distance_from_string_with_kerning_correction<N: Integer>(
+    input: &str,
+) -> Result<(N, N), ParseError> {
+    let (time, distance) = split_times_and_distances(input)?;
+    let parse_number = |number: &str| {
+        number
+            .char_indices()
+            .filter(|(_, c)| !c.is_whitespace())
+            .try_fold(N::ZERO, |total, (index, c)| match c.to_digit(10) {
+                Some(digit) => total
+                    .try_mul(N::from(10))
+                    .and_then(|total| total.try_add(N::from(digit as u8)))
+                    .map_err(|_| ParseError::new(input, number.trim(), "a smaller number")),
+                None => Err(ParseError::new(input, &number[index..], "a digit")),
+            })
+    };
+    Ok((parse_number(time)?, parse_number(distance)?))
+}
+
+fn split_times_and_distances(input: &str) -> Result<(&str, &str), ParseError> {
+    let trimmed = input.trim();
+    let mut iter = trimmed.lines().map(|line| {
+        line.split_once(':')
+            .map(|(_, numbers)| numbers)
+            .ok_or_else(|| ParseError::at_end(line, "\":\" after the label").within(input, line))
+    });
+    let missing = |expected| ParseError::at_end(trimmed, expected).within(input, trimmed);
+    let times = iter.next().ok_or_else(|| missing("a line of times"))??;
+    let distances = iter
+        .next()
+        .ok_or_else(|| missing("a line of distances"))??;
+    Ok((times, distances))
+}
+
+fn calculate_distance_if_button_held_for<N: Integer>(time: N, limit: N) -> Result<N, Overflow> {
+    limit.try_sub(time)?.try_mul(time)
+}
+
+fn calculate_number_of_ways_to_win<N: Integer>(limit: N, record: N) -> Result<N, Overflow> {
+    // no time goes further than holding the button for half the race, so if that fits nothing
+    // else overflows and the loop can skip checking every step.
+    calculate_distance_if_button_held_for(limit / N::from(2), limit)?;
+    let (mut time, mut ways) = (N::ZERO, N::ZERO);
+    loop {
+        if (limit - time) * time > record {
+            ways = ways + N::ONE;
+        }
+        if time == limit {
+            return Ok(ways);
+        }
+        time = time + N::ONE;
+    }
+}
+
+fn calculate_number_of_ways_to_win_closed_form<N: Integer>(
+    limit: N,
+    record: N,
+) -> Result<N, Overflow> {
+    let wins =
+        |time| Ok::<_, Overflow>(calculate_distance_if_button_held_for(time, limit)? > record);
+    let two = N::from(2);
+    let middle = limit / two;
+    if !wins(middle)? {
+        return Ok(N::ZERO);
+    }
+    // the distance is symmetric around the middle, so the winning times run from the first
+    // root of time * (limit - time) = record to its mirror image. the integer root is only a
+    // guess which is corrected either way.
+    let discriminant = limit.try_mul(limit)?.try_sub(N::from(4).try_mul(record)?)?;
+    let mut first = (limit.try_sub(discriminant.isqrt())? / two).min(middle);
+    while !wins(first)? {
+        first = first.try_add(N::ONE)?;
+    }
+    while first > N::ZERO && wins(first.try_sub(N::ONE)?)? {
+        first = first.try_sub(N::ONE)?;
+    }
+    limit.try_sub(two.try_mul(first)?)?.try_add(N::ONE)
+}
+
+fn calculate_margin_of_error<N: Integer>(limits: &[N], records: &[N]) -> Result<N, Overflow> {
+    limits
+        .iter()
+        .zip(records.iter())
+        .try_fold(N::ONE, |product, (limit, record)| {
+            product.try_mul(calculate_number_of_ways_to_win(*limit, *record)?)
+        })
+}
+
+fn solve_part_1<N: Integer<Wide = Wide>>(races: &Races) -> Result<N, Overflow> {
+    calculate_margin_of_error(
+        &num::narrow_all(&races.limits)?,
+        &num::narrow_all(&races.records)?,
+    )
+}
+
+fn solve_part_2<N: Integer<Wide = Wide>>(races: &Races) -> Result<N, Overflow> {
+    let (limit, record) = races.kerning_corrected;
+    calculate_margin_of_error(&[N::narrow(limit)?], &[N::narrow(record)?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_times_and_distances_from_string() {
+        const INPUT: &str = "
+        Time:      7  15   30
+        Distance:  9  40  200
+        ";
+        const EXPECTED_TIMES: &[IntegerType] = &[7, 15, 30];
+        const EXPECTED_DISTANCES: &[IntegerType] = &[9, 40, 200];
+        let (times, distances) =
+            parse_times_and_distances_from_string::<IntegerType>(INPUT).unwrap();
+        assert_eq!(&times, EXPECTED_TIMES);
+        assert_eq!(&distances, EXPECTED_DISTANCES);
+    }
+
+    #[test]
+    fn test_parse_times_and_distances_from_string_missing_distance() {
+        const INPUT: &str = "Time: 7 15\nDistance: 9";
+        const EXPECTED: &str = "line 1, column 9: expected a distance for every time, found \"15\"";
+        let output = parse_times_and_distances_from_string::<IntegerType>(INPUT)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_times_and_distances_from_string_missing_time() {
+        const INPUT: &str = "
+        Time:      7  15
+        Distance:  9  40  200
+        ";
+        const EXPECTED: &str =
+            "line 3, column 27: expected a time for every distance, found \"200\"";
+        let output = parse_times_and_distances_from_string::<IntegerType>(INPUT)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_calculate_number_of_ways_to_win_closed_form() {
+        const INPUT: &[(IntegerType, IntegerType)] = &[
+            (7, 9),
+            (15, 40),
+            (30, 200),
+            (71530, 940200),
+            (7, 12),
+            (0, 0),
+        ];
+        let expected: Vec<Result<IntegerType, _>> = INPUT
+            .iter()
+            .map(|(limit, record)| calculate_number_of_ways_to_win(*limit, *record))
+            .collect();
+        let output: Vec<_> = INPUT
+            .iter()
+            .map(|(limit, record)| calculate_number_of_ways_to_win_closed_form(*limit, *record))
+            .collect();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_calculate_distance_if_button_held_for_0() {
+        const INPUT_TIME: IntegerType = 0;
+        const INPUT_LIMIT: IntegerType = 7;
+        const EXPECTED: IntegerType = 0;
+        let output = calculate_distance_if_button_held_for(INPUT_TIME, INPUT_LIMIT);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_calculate_distance_if_button_held_for_1() {
+        const INPUT_TIME: IntegerType = 1;
+        const INPUT_LIMIT: IntegerType = 7;
+        const EXPECTED: IntegerType = 6;
+        let output = calculate_distance_if_button_held_for(INPUT_TIME, INPUT_LIMIT);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_calculate_distance_if_button_held_for_2() {
+        const INPUT_TIME: IntegerType = 2;
+        const INPUT_LIMIT: IntegerType = 7;
+        const EXPECTED: IntegerType = 10;
+        let output = calculate_distance_if_button_held_for(INPUT_TIME, INPUT_LIMIT);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_calculate_distance_if_button_held_for_3() {
+        const INPUT_TIME: IntegerType = 3;
+        const INPUT_LIMIT: IntegerType = 7;
+        const EXPECTED: IntegerType = 12;
+        let output = calculate_distance_if_button_held_for(INPUT_TIME, INPUT_LIMIT);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_calculate_distance_if_button_held_for_4() {
+        const INPUT_TIME: IntegerType = 4;
+        const INPUT_LIMIT: IntegerType = 7;
+        const EXPECTED: IntegerType = 12;
+        let output = calculate_distance_if_button_held_for(INPUT_TIME, INPUT_LIMIT);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_calculate_distance_if_button_held_for_5() {
+        const INPUT_TIME: IntegerType = 5;
+        const INPUT_LIMIT: IntegerType = 7;
+        const EXPECTED: IntegerType = 10;
+        let output = calculate_distance_if_button_held_for(INPUT_TIME, INPUT_LIMIT);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_calculate_distance_if_button_held_for_6() {
+        const INPUT_TIME: IntegerType = 6;
+        const INPUT_LIMIT: IntegerType = 7;
+        const EXPECTED: IntegerType = 6;
+        let output = calculate_distance_if_button_held_for(INPUT_TIME, INPUT_LIMIT);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_calculate_distance_if_button_held_for_7() {
+        const INPUT_TIME: IntegerType = 7;
+        const INPUT_LIMIT: IntegerType = 7;
+        const EXPECTED: IntegerType = 0;
+        let output = calculate_distance_if_button_held_for(INPUT_TIME, INPUT_LIMIT);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_calculate_number_of_ways_for_race_case_1() {
+        const INPUT_LIMIT: IntegerType = 7;
+        const INPUT_RECORD: IntegerType = 9;
+        const EXPECTED: IntegerType = 4;
+        let output = calculate_number_of_ways_to_win(INPUT_LIMIT, INPUT_RECORD);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_calculate_number_of_ways_for_race_case_2() {
+        const INPUT_LIMIT: IntegerType = 15;
+        const INPUT_RECORD: IntegerType = 40;
+        const EXPECTED: IntegerType = 8;
+        let output = calculate_number_of_ways_to_win(INPUT_LIMIT, INPUT_RECORD);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_calculate_number_of_ways_for_race_case_3() {
+        const INPUT_LIMIT: IntegerType = 30;
+        const INPUT_RECORD: IntegerType = 200;
+        const EXPECTED: IntegerType = 9;
+        let output = calculate_number_of_ways_to_win(INPUT_LIMIT, INPUT_RECORD);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_calculate_margin_of_error() {
+        const INPUT_LIMITS: &[IntegerType] = &[7, 15, 30];
+        const INPUT_RECORDS: &[IntegerType] = &[9, 40, 200];
+        const EXPECTED: IntegerType = 288;
+        let output = calculate_margin_of_error(INPUT_LIMITS, INPUT_RECORDS);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        const INPUT: &str = "
+        Time:      7  15   30
+        Distance:  9  40  200
+        ";
+        const EXPECTED: IntegerType = 288;
+        let output = solve_part_1(&parse_races(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_parse_time_and_distance_from_string_with_kerning_correction() {
+        const INPUT: &str = "
+        Time:      7  15   30
+        Distance:  9  40  200
+        ";
+        const EXPECTED_TIME: IntegerType = 71530;
+        const EXPECTED_DISTANCE: IntegerType = 940200;
+        let (time, distance) =
+            parse_time_and_distance_from_string_with_kerning_correction::<IntegerType>(INPUT)
+                .unwrap();
+        assert_eq!(time, EXPECTED_TIME);
+        assert_eq!(distance, EXPECTED_DISTANCE);
+    }
+
+    #[test]
+    fn test_parse_races_missing_distances() {
+        const INPUT: &str = "
+        Time:      7  15   30
+        ";
+        const EXPECTED: &str = "line 2, column 30: expected a line of distances, found end of line";
+        let output = parse_races(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_time_and_distance_from_string_with_kerning_correction_invalid_digit() {
+        const INPUT: &str = "
+        Time:      7  15   30
+        Distance:  9  4O  200
+        ";
+        const EXPECTED: &str = "line 3, column 24: expected a digit, found \"O  200\"";
+        let output =
+            parse_time_and_distance_from_string_with_kerning_correction::<IntegerType>(INPUT)
+                .unwrap_err()
+                .to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        const INPUT: &str = "
+        Time:      7  15   30
+        Distance:  9  40  200
+        ";
+        const EXPECTED: IntegerType = 71503;
+        let output = solve_part_2(&parse_races(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_widens_on_overflow() {
+        const INPUT: &str = "
+        Time:      100000 100000 100000 100000
+        Distance:  0      0      0      0
+        ";
+        let races = parse_races(INPUT).unwrap();
+        let output = Day06::solve_part_1(&races);
+        assert_eq!(output, Ok(Widened::Wide(99996000059999600001)));
+        assert_eq!(Day06ClosedForm::solve_part_1(&races), output);
+    }
+
+    #[test]
+    fn test_closed_form_widens_on_overflow() {
+        // the answer fits in a u64, but the square of the time doesn't.
+        const INPUT: &str = "
+        Time:      5000000000
+        Distance:  0
+        ";
+        let output = Day06ClosedForm::solve_part_2(&parse_races(INPUT).unwrap());
+        assert_eq!(output, Ok(Widened::Wide(4999999999)));
+    }
+}
@@ -1,6 +1,12 @@
+use alloc::vec::Vec;
 use core::cmp::Ordering;
+use core::fmt;
 
-const INPUT: &str = include_str!("../../data/day07.txt");
+use crate::num::{self, Integer, Overflow, Widened};
+use crate::trace;
+use crate::{ParseError, Solution, SolveError};
+
+type IntegerType = u32;
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 enum Value {
@@ -31,43 +37,93 @@ enum HandType {
     HighCard,
 }
 
-#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
-struct Hand {
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Hand {
     hand_type: HandType,
     values: [Value; 5],
     counts: [Option<(Value, u8)>; 5],
 }
 
-fn main() {
-    println!("Part 1 => {}", solve_part_1(INPUT));
-    println!("Part 2 => {}", solve_part_2(INPUT));
+/// The cards of the hand as they were written.
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for value in self.values {
+            let card = match value {
+                Value::Ace => 'A',
+                Value::King => 'K',
+                Value::Queen => 'Q',
+                Value::Jack | Value::Joker => 'J',
+                Value::Ten => 'T',
+                Value::Nine => '9',
+                Value::Eight => '8',
+                Value::Seven => '7',
+                Value::Six => '6',
+                Value::Five => '5',
+                Value::Four => '4',
+                Value::Three => '3',
+                Value::Two => '2',
+            };
+            write!(f, "{card}")?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+    type Parsed<'a> = Vec<(Hand, u32)>;
+    type Part1 = Widened<IntegerType>;
+    type Part2 = Widened<IntegerType>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_hands_and_bids(input).map_err(|error| error.on_day(Self::DAY))
+    }
+
+    fn solve_part_1(hands_and_bids: &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(num::widen(
+            || solve_part_1(hands_and_bids),
+            || solve_part_1(hands_and_bids),
+        )?)
+    }
+
+    fn solve_part_2(hands_and_bids: &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(num::widen(
+            || solve_part_2(hands_and_bids),
+            || solve_part_2(hands_and_bids),
+        )?)
+    }
 }
 
-fn solve_part_1(input: &str) -> u32 {
-    let mut hands_and_bids = parse_hands_and_bids(input);
+fn solve_part_1<N: Integer + From<u32>>(hands_and_bids: &[(Hand, u32)]) -> Result<N, Overflow> {
+    let mut hands_and_bids = hands_and_bids.to_vec();
     hands_and_bids.sort_by(|hand_1, hand_2| hand_2.0.cmp(&hand_1.0));
-    hands_and_bids
-        .into_iter()
-        .enumerate()
-        .map(|(index, (_, bid))| (index as u32 + 1) * bid)
-        .sum()
+    total_winnings(&hands_and_bids)
 }
 
-fn solve_part_2(input: &str) -> u32 {
-    let mut hands_and_bids = parse_hands_and_bids(input);
+fn solve_part_2<N: Integer + From<u32>>(hands_and_bids: &[(Hand, u32)]) -> Result<N, Overflow> {
+    let mut hands_and_bids = hands_and_bids.to_vec();
     hands_and_bids
         .iter_mut()
         .for_each(|(hand, _)| upgrade_hand_with_jacks_as_jokers(hand));
     hands_and_bids.sort_by(|hand_1, hand_2| hand_2.0.cmp(&hand_1.0));
-    hands_and_bids
-        .into_iter()
-        .enumerate()
-        .map(|(index, (_, bid))| (index as u32 + 1) * bid)
-        .sum()
+    total_winnings(&hands_and_bids)
 }
 
-fn parse_value(input: char) -> Value {
-    match input {
+/// Adds up each bid times its rank, for hands sorted from the weakest to the strongest.
+fn total_winnings<N: Integer + From<u32>>(ranked: &[(Hand, u32)]) -> Result<N, Overflow> {
+    ranked
+        .iter()
+        .zip(1u32..)
+        .try_fold(N::ZERO, |total, ((_, bid), rank)| {
+            total.try_add(N::from(rank).try_mul(N::from(*bid))?)
+        })
+}
+
+fn parse_value(input: char) -> Option<Value> {
+    let value = match input {
         'A' => Value::Ace,
         'K' => Value::King,
         'Q' => Value::Queen,
@@ -81,19 +137,24 @@ fn parse_value(input: char) -> Value {
         '4' => Value::Four,
         '3' => Value::Three,
         '2' => Value::Two,
-        _ => unimplemented!("Not implemented for {}", input),
-    }
+        _ => return None,
+    };
+    Some(value)
 }
 
-fn parse_hand_values(input: &str) -> [Value; 5] {
-    let mut chars = input.chars().map(parse_value);
-    [
-        chars.next().unwrap(),
-        chars.next().unwrap(),
-        chars.next().unwrap(),
-        chars.next().unwrap(),
-        chars.next().unwrap(),
-    ]
+fn parse_hand_values(input: &str) -> Result<[Value; 5], ParseError> {
+    let mut values = [Value::Two; 5];
+    let mut chars = input.char_indices();
+    for value in &mut values {
+        let (index, c) = chars
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, "five cards"))?;
+        *value = parse_value(c).ok_or_else(|| ParseError::new(input, &input[index..], "a card"))?;
+    }
+    match chars.next() {
+        Some((index, _)) => Err(ParseError::new(input, &input[index..], "five cards")),
+        None => Ok(values),
+    }
 }
 
 fn count_values(hand: &[Value; 5]) -> [Option<(Value, u8)>; 5] {
@@ -149,26 +210,33 @@ fn determine_hand_type(counts: &[Option<(Value, u8)>; 5]) -> HandType {
     }
 }
 
-fn parse_hand(input: &str) -> Hand {
-    let values = parse_hand_values(input);
+fn parse_hand(input: &str) -> Result<Hand, ParseError> {
+    let values = parse_hand_values(input)?;
     let counts = count_values(&values);
     let hand_type = determine_hand_type(&counts);
-    Hand {
+    Ok(Hand {
         hand_type,
         counts,
         values,
-    }
+    })
 }
 
-fn parse_hands_and_bids(input: &str) -> Vec<(Hand, u32)> {
+fn parse_hands_and_bids(input: &str) -> Result<Vec<(Hand, u32)>, ParseError> {
     input
         .trim()
         .lines()
         .map(|line| {
-            let mut parts = line.split_whitespace();
-            let hand = parse_hand(parts.next().unwrap());
-            let bid: u32 = parts.next().unwrap().parse().unwrap();
-            (hand, bid)
+            let line = line.trim();
+            let (hand, bid) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at_end(line, "a hand followed by a bid"))
+                .map_err(|error| error.within(input, line))?;
+            let hand = parse_hand(hand).map_err(|error| error.within(input, hand))?;
+            let bid = bid
+                .trim()
+                .parse()
+                .map_err(|_| ParseError::new(input, bid.trim(), "a bid"))?;
+            Ok((hand, bid))
         })
         .collect()
 }
@@ -180,7 +248,19 @@ fn upgrade_hand_with_jacks_as_jokers(input: &mut Hand) {
     } else {
         input.counts[0] = Some((Value::Ace, count));
     }
-    input.hand_type = determine_hand_type(&input.counts);
+    let hand_type = determine_hand_type(&input.counts);
+    if count > 0 {
+        trace::event(
+            "joker upgrade",
+            &[
+                ("hand", &input),
+                ("jokers", &count),
+                ("from", &format_args!("{:?}", input.hand_type)),
+                ("to", &format_args!("{hand_type:?}")),
+            ],
+        );
+    }
+    input.hand_type = hand_type;
     input.values.iter_mut().for_each(|value| {
         if *value == Value::Jack {
             *value = Value::Joker
@@ -195,7 +275,7 @@ mod tests {
     #[test]
     fn test_parse_value_ace() {
         const INPUT: char = 'A';
-        const EXPECTED: Value = Value::Ace;
+        const EXPECTED: Option<Value> = Some(Value::Ace);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -203,7 +283,7 @@ mod tests {
     #[test]
     fn test_parse_value_king() {
         const INPUT: char = 'K';
-        const EXPECTED: Value = Value::King;
+        const EXPECTED: Option<Value> = Some(Value::King);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -211,7 +291,7 @@ mod tests {
     #[test]
     fn test_parse_value_queen() {
         const INPUT: char = 'Q';
-        const EXPECTED: Value = Value::Queen;
+        const EXPECTED: Option<Value> = Some(Value::Queen);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -219,7 +299,7 @@ mod tests {
     #[test]
     fn test_parse_value_jack() {
         const INPUT: char = 'J';
-        const EXPECTED: Value = Value::Jack;
+        const EXPECTED: Option<Value> = Some(Value::Jack);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -227,7 +307,7 @@ mod tests {
     #[test]
     fn test_parse_value_ten() {
         const INPUT: char = 'T';
-        const EXPECTED: Value = Value::Ten;
+        const EXPECTED: Option<Value> = Some(Value::Ten);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -235,7 +315,7 @@ mod tests {
     #[test]
     fn test_parse_value_nine() {
         const INPUT: char = '9';
-        const EXPECTED: Value = Value::Nine;
+        const EXPECTED: Option<Value> = Some(Value::Nine);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -243,7 +323,7 @@ mod tests {
     #[test]
     fn test_parse_value_eight() {
         const INPUT: char = '8';
-        const EXPECTED: Value = Value::Eight;
+        const EXPECTED: Option<Value> = Some(Value::Eight);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -251,7 +331,7 @@ mod tests {
     #[test]
     fn test_parse_value_seven() {
         const INPUT: char = '7';
-        const EXPECTED: Value = Value::Seven;
+        const EXPECTED: Option<Value> = Some(Value::Seven);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -259,7 +339,7 @@ mod tests {
     #[test]
     fn test_parse_value_six() {
         const INPUT: char = '6';
-        const EXPECTED: Value = Value::Six;
+        const EXPECTED: Option<Value> = Some(Value::Six);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -267,7 +347,7 @@ mod tests {
     #[test]
     fn test_parse_value_five() {
         const INPUT: char = '5';
-        const EXPECTED: Value = Value::Five;
+        const EXPECTED: Option<Value> = Some(Value::Five);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -275,7 +355,7 @@ mod tests {
     #[test]
     fn test_parse_value_four() {
         const INPUT: char = '4';
-        const EXPECTED: Value = Value::Four;
+        const EXPECTED: Option<Value> = Some(Value::Four);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -283,7 +363,7 @@ mod tests {
     #[test]
     fn test_parse_value_three() {
         const INPUT: char = '3';
-        const EXPECTED: Value = Value::Three;
+        const EXPECTED: Option<Value> = Some(Value::Three);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -291,7 +371,7 @@ mod tests {
     #[test]
     fn test_parse_value_two() {
         const INPUT: char = '2';
-        const EXPECTED: Value = Value::Two;
+        const EXPECTED: Option<Value> = Some(Value::Two);
         let output = parse_value(INPUT);
         assert_eq!(output, EXPECTED);
     }
@@ -300,7 +380,45 @@ mod tests {
     fn test_parse_hand_values() {
         const INPUT: &str = "AT52A";
         const EXPECTED: [Value; 5] = [Value::Ace, Value::Ten, Value::Five, Value::Two, Value::Ace];
-        let output = parse_hand_values(INPUT);
+        let output = parse_hand_values(INPUT).unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_value_invalid() {
+        const INPUT: char = 'X';
+        const EXPECTED: Option<Value> = None;
+        let output = parse_value(INPUT);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_hand_values_too_short() {
+        const INPUT: &str = "AT52";
+        const EXPECTED: &str = "line 1, column 5: expected five cards, found end of line";
+        let output = parse_hand_values(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_hands_and_bids_invalid_card() {
+        const INPUT: &str = "
+        32T3K 765
+        T55X5 684
+        ";
+        const EXPECTED: &str = "line 3, column 12: expected a card, found \"X5\"";
+        let output = parse_hands_and_bids(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_hands_and_bids_invalid_bid() {
+        const INPUT: &str = "
+        32T3K 765
+        T55J5 6a4
+        ";
+        const EXPECTED: &str = "line 3, column 15: expected a bid, found \"6a4\"";
+        let output = parse_hands_and_bids(INPUT).unwrap_err().to_string();
         assert_eq!(output, EXPECTED);
     }
 
@@ -532,7 +650,7 @@ mod tests {
                 Value::Nine,
             ],
         };
-        let output = parse_hand(INPUT);
+        let output = parse_hand(INPUT).unwrap();
         assert_eq!(output, EXPECTED);
     }
 
@@ -556,7 +674,7 @@ mod tests {
                 Value::Nine,
             ],
         };
-        let output = parse_hand(INPUT);
+        let output = parse_hand(INPUT).unwrap();
         assert_eq!(output, EXPECTED);
     }
 
@@ -580,7 +698,7 @@ mod tests {
                 Value::Seven,
             ],
         };
-        let output = parse_hand(INPUT);
+        let output = parse_hand(INPUT).unwrap();
         assert_eq!(output, EXPECTED);
     }
 
@@ -604,7 +722,7 @@ mod tests {
                 Value::Nine,
             ],
         };
-        let output = parse_hand(INPUT);
+        let output = parse_hand(INPUT).unwrap();
         assert_eq!(output, EXPECTED);
     }
 
@@ -628,7 +746,7 @@ mod tests {
                 Value::Seven,
             ],
         };
-        let output = parse_hand(INPUT);
+        let output = parse_hand(INPUT).unwrap();
         assert_eq!(output, EXPECTED);
     }
 
@@ -652,7 +770,7 @@ mod tests {
                 Value::Eight,
             ],
         };
-        let output = parse_hand(INPUT);
+        let output = parse_hand(INPUT).unwrap();
         assert_eq!(output, EXPECTED);
     }
 
@@ -676,7 +794,7 @@ mod tests {
                 Value::Eight,
             ],
         };
-        let output = parse_hand(INPUT);
+        let output = parse_hand(INPUT).unwrap();
         assert_eq!(output, EXPECTED);
     }
 
@@ -791,7 +909,7 @@ mod tests {
                 483,
             ),
         ];
-        let output = parse_hands_and_bids(INPUT);
+        let output = parse_hands_and_bids(INPUT).unwrap();
         assert_eq!(&output, EXPECTED);
     }
 
@@ -804,9 +922,9 @@ mod tests {
         KTJJT 220
         QQQJA 483
         ";
-        const EXPECTED: u32 = 6440;
-        let output = solve_part_1(INPUT);
-        assert_eq!(output, EXPECTED);
+        const EXPECTED: IntegerType = 6440;
+        let output = solve_part_1::<IntegerType>(&parse_hands_and_bids(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
     }
 
     #[test]
@@ -885,6 +1003,15 @@ mod tests {
         assert_eq!(input, EXPECTED);
     }
 
+    #[test]
+    fn test_upgrade_hand_with_jacks_as_jokers_trace() {
+        const EXPECTED: &str = "joker upgrade hand=KTJJT jokers=2 from=TwoPair to=FourOfAKind";
+        let mut input = parse_hand("KTJJT").unwrap();
+        let ((), events) = trace::collect(|| upgrade_hand_with_jacks_as_jokers(&mut input));
+        let events: Vec<_> = events.iter().map(ToString::to_string).collect();
+        assert_eq!(events, [EXPECTED]);
+    }
+
     #[test]
     fn test_upgrade_hand_with_jacks_as_jokers_no_jacks() {
         let mut input = Hand {
@@ -962,8 +1089,26 @@ mod tests {
         KTJJT 220
         QQQJA 483
         ";
-        const EXPECTED: u32 = 5905;
-        let output = solve_part_2(INPUT);
-        assert_eq!(output, EXPECTED);
+        const EXPECTED: IntegerType = 5905;
+        let output = solve_part_2::<IntegerType>(&parse_hands_and_bids(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_widens_on_overflow() {
+        // the stronger hand's bid times its rank of 2 is past the largest u32, while the bid fits.
+        const INPUT: &str = "
+        32T3K 1
+        KK677 4000000000
+        ";
+        let hands_and_bids = parse_hands_and_bids(INPUT).unwrap();
+        assert_eq!(
+            Day07::solve_part_1(&hands_and_bids),
+            Ok(Widened::Wide(8000000001))
+        );
+        assert_eq!(
+            Day07::solve_part_2(&hands_and_bids),
+            Ok(Widened::Wide(8000000001))
+        );
     }
 }
@@ -0,0 +1,457 @@
+#[cfg(not(feature = "std"))]
+use alloc::collections::{BTreeMap as HashMap, BTreeSet as HashSet};
+use alloc::format;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::num::{self, Integer, Overflow, Widened};
+use crate::parallel;
+use crate::{NoAnswer, ParseError, Solution, SolveError};
+
+type IntegerType = u64;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+type Mapping<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+    type Parsed<'a> = (Vec<Direction>, Mapping<'a>);
+    type Part1 = Widened<IntegerType>;
+    type Part2 = Widened<IntegerType>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input).map_err(|error| error.on_day(Self::DAY))
+    }
+
+    fn solve_part_1((sequence, mapping): &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(Widened::Narrow(solve_part_1(sequence, mapping)?))
+    }
+
+    fn solve_part_2((sequence, mapping): &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+        let steps = ghost_steps(sequence, mapping)?;
+        Ok(num::widen(
+            || lowest_common_multiple_of(&steps),
+            || lowest_common_multiple_of(&steps),
+        )?)
+    }
+}
+
+/// Walks every ghost at once until they all stand on a node ending in Z, as the puzzle
+/// describes, instead of relying on every ghost looping like [`Day08`] does. Every position the
+/// ghosts have been in is kept to notice when they go round in circles, so this is only for
+/// small inputs.
+pub struct Day08Walk;
+
+impl Solution for Day08Walk {
+    const DAY: u8 = Day08::DAY;
+    const TITLE: &'static str = Day08::TITLE;
+    type Parsed<'a> = (Vec<Direction>, Mapping<'a>);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Day08::parse(input)
+    }
+
+    fn solve_part_1((sequence, mapping): &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(solve_part_1(sequence, mapping)?)
+    }
+
+    fn solve_part_2((sequence, mapping): &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+        let mut current: Vec<&str> = mapping
+            .keys()
+            .filter(|key| key.ends_with('A'))
+            .copied()
+            .collect();
+        let mut visited = HashSet::new();
+        let mut moves = sequence.iter().enumerate().cycle();
+        let mut count = 0;
+        while !current.iter().all(|node| node.ends_with('Z')) {
+            let Some((index, direction)) = moves.next() else {
+                return Err(NoAnswer::new("there are no moves to walk with".into()).into());
+            };
+            if !visited.insert((current.clone(), index)) {
+                let reason =
+                    "the ghosts go round in circles without all reaching a node ending in Z";
+                return Err(NoAnswer::new(reason.into()).into());
+            }
+            for node in &mut current {
+                *node = step(node, *direction, mapping);
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+fn solve_part_1(sequence: &[Direction], mapping: &Mapping) -> Result<u64, NoAnswer> {
+    calculate_steps("AAA", |current| current == "ZZZ", sequence, mapping)
+}
+
+/// The steps each ghost takes to first reach a node ending in Z.
+fn ghost_steps(sequence: &[Direction], mapping: &Mapping) -> Result<Vec<u64>, NoAnswer> {
+    let starts: Vec<_> = mapping.keys().filter(|key| key.ends_with('A')).collect();
+    parallel::map(&starts, |key| {
+        calculate_steps(key, |current| current.ends_with('Z'), sequence, mapping)
+    })
+    .into_iter()
+    .collect()
+}
+
+/// The first step that all ghosts line up at, which is when every cycle does.
+fn lowest_common_multiple_of<N: Integer + From<u64>>(steps: &[u64]) -> Result<N, Overflow> {
+    steps.iter().try_fold(N::ONE, |lcm, steps| {
+        lowest_common_multiple(lcm, N::from(*steps))
+    })
+}
+
+fn greatest_common_divisor<N: Integer>(a: N, b: N) -> N {
+    if b == N::ZERO {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
+}
+
+fn lowest_common_multiple<N: Integer>(a: N, b: N) -> Result<N, Overflow> {
+    (a / greatest_common_divisor(a, b)).try_mul(b)
+}
+
+/// Counts the steps from `from` to the first node that `terminates`. Fails if the walk comes back
+/// to a node at the same move in the sequence first, as it would then go round forever.
+fn calculate_steps(
+    from: &str,
+    terminates: impl Fn(&str) -> bool,
+    sequence: &[Direction],
+    mapping: &Mapping,
+) -> Result<u64, NoAnswer> {
+    let mut visited = HashSet::new();
+    let mut moves = sequence.iter().enumerate().cycle();
+    let mut count = 0;
+    let mut current = from;
+    while !terminates(current) {
+        let Some((index, direction)) = moves.next() else {
+            return Err(NoAnswer::new(format!(
+                "there are no moves to walk from {from} with"
+            )));
+        };
+        if !visited.insert((current, index)) {
+            return Err(NoAnswer::new(format!(
+                "the walk from {from} goes round in circles from {current} without reaching the end"
+            )));
+        }
+        current = step(current, *direction, mapping);
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// The node one move on from `node`, which the parser has checked is in the network.
+fn step<'a>(node: &str, direction: Direction, mapping: &Mapping<'a>) -> &'a str {
+    match direction {
+        Direction::Left => mapping[node].0,
+        Direction::Right => mapping[node].1,
+    }
+}
+
+fn parse_move_sequence(input: &str) -> Result<Vec<Direction>, ParseError> {
+    input
+        .char_indices()
+        .map(|(index, c)| match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(ParseError::new(input, &input[index..], "L or R")),
+        })
+        .collect()
+}
+
+fn parse_move(input: &str) -> Result<(&str, &str, &str), ParseError> {
+    let (label, rest) = input
+        .split_once(" = (")
+        .ok_or_else(|| ParseError::at_end(input, "\" = (\" after the node label"))?;
+    let rest = rest
+        .strip_suffix(')')
+        .ok_or_else(|| ParseError::at_end(input, "\")\" at the end of the node"))?;
+    let (left, right) = rest
+        .split_once(", ")
+        .ok_or_else(|| ParseError::new(input, rest, "\", \" between the left and right nodes"))?;
+    Ok((label, left, right))
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Direction>, Mapping<'_>), ParseError> {
+    let mut lines = input.lines().filter_map(|line| {
+        let line = line.trim();
+        if line.is_empty() {
+            None
+        } else {
+            Some(line)
+        }
+    });
+    let sequence = lines
+        .next()
+        .ok_or_else(|| ParseError::at_end(input, "a move sequence"))?;
+    let sequence = parse_move_sequence(sequence).map_err(|error| error.within(input, sequence))?;
+    let moves = lines
+        .map(|line| parse_move(line).map_err(|error| error.within(input, line)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let hm: Mapping = moves
+        .iter()
+        .map(|(label, left, right)| (*label, (*left, *right)))
+        .collect();
+    for (_, left, right) in moves {
+        for node in [left, right] {
+            if !hm.contains_key(node) {
+                return Err(ParseError::new(
+                    input,
+                    node,
+                    "a node defined in the network",
+                ));
+            }
+        }
+    }
+    if !hm.contains_key("AAA") {
+        return Err(ParseError::at_end(
+            input,
+            "a node labelled AAA to start from",
+        ));
+    }
+    Ok((sequence, hm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+
+    #[test]
+    fn test_parse_move_sequence() {
+        const INPUT: &str = "LLR";
+        const EXPECTED: &[Direction] = &[Direction::Left, Direction::Left, Direction::Right];
+        let output = parse_move_sequence(INPUT).unwrap();
+        assert_eq!(&output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_move() {
+        const INPUT: &str = "CCC = (ZZZ, GGG)";
+        const EXPECTED: (&str, &str, &str) = ("CCC", "ZZZ", "GGG");
+        let output = parse_move(INPUT).unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        const INPUT: &str = "
+        LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ZZZ = (ZZZ, ZZZ)
+        ";
+        const EXPECTED_SEQUENCE: &[Direction] =
+            &[Direction::Left, Direction::Left, Direction::Right];
+        const EXPECTED_MAPPING: &[(&str, (&str, &str))] = &[
+            ("AAA", ("BBB", "BBB")),
+            ("BBB", ("AAA", "ZZZ")),
+            ("ZZZ", ("ZZZ", "ZZZ")),
+        ];
+        let (sequence, mapping) = parse_input(INPUT).unwrap();
+        let mut mapping: Vec<_> = mapping.into_iter().collect();
+        mapping.sort();
+        assert_eq!(&sequence, EXPECTED_SEQUENCE);
+        assert_eq!(&mapping, EXPECTED_MAPPING);
+    }
+
+    #[test]
+    fn test_parse_move_sequence_invalid_direction() {
+        const INPUT: &str = "LLRU";
+        const EXPECTED: &str = "line 1, column 4: expected L or R, found \"U\"";
+        let output = parse_move_sequence(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_input_missing_separator() {
+        const INPUT: &str = "
+        LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA ZZZ)
+        ZZZ = (ZZZ, ZZZ)
+        ";
+        const EXPECTED: &str =
+            "line 5, column 16: expected \", \" between the left and right nodes, found \"AAA ZZZ\"";
+        let output = parse_input(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_input_undefined_node() {
+        const INPUT: &str = "
+        LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ";
+        const EXPECTED: &str =
+            "line 5, column 21: expected a node defined in the network, found \"ZZZ\"";
+        let output = parse_input(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_input_missing_start() {
+        const INPUT: &str = "
+        LR
+
+        11A = (11B, 11B)
+        11B = (11Z, 11Z)
+        11Z = (11A, 11A)
+        ";
+        const EXPECTED: &str =
+            "line 7, column 9: expected a node labelled AAA to start from, found end of line";
+        let output = parse_input(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_calculate_steps_goes_round_in_circles() {
+        // ZZZ can't be reached, so the walk comes back to AAA at the first move.
+        const INPUT: &str = "
+        LR
+
+        AAA = (BBB, BBB)
+        BBB = (CCC, AAA)
+        CCC = (CCC, CCC)
+        ZZZ = (ZZZ, ZZZ)
+        ";
+        const EXPECTED: &str = "day 8, part 1: the walk from AAA goes round in circles from AAA \
+            without reaching the end";
+        let parsed = Day08::parse(INPUT).unwrap();
+        let output = Day08::solve_part_1(&parsed)
+            .map_err(|error| error.on_day(8).in_part(Part::One).to_string());
+        assert_eq!(output, Err(EXPECTED.to_string()));
+        let output = Day08Walk::solve_part_1(&parsed).map_err(|error| error.to_string());
+        assert_eq!(output, Err(EXPECTED[15..].to_string()));
+    }
+
+    #[test]
+    fn test_walk_goes_round_in_circles() {
+        // both ghosts reach a node ending in Z, but never at the same time.
+        const INPUT: &str = "
+        L
+
+        AAA = (ZZZ, ZZZ)
+        ZZZ = (AAA, AAA)
+        11A = (11A, 11A)
+        ";
+        let parsed = Day08::parse(INPUT).unwrap();
+        let output = Day08Walk::solve_part_2(&parsed).map_err(|error| error.to_string());
+        assert_eq!(
+            output,
+            Err(
+                "the ghosts go round in circles without all reaching a node ending in Z"
+                    .to_string()
+            )
+        );
+        let output = Day08::solve_part_2(&parsed).map_err(|error| error.to_string());
+        assert!(output
+            .unwrap_err()
+            .starts_with("the walk from 11A goes round"));
+    }
+
+    #[test]
+    fn test_greatest_common_divisor() {
+        const INPUT_A: u64 = 12;
+        const INPUT_B: u64 = 18;
+        const EXPECTED: u64 = 6;
+        let output = greatest_common_divisor(INPUT_A, INPUT_B);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_lowest_common_multiple() {
+        const INPUT_A: u64 = 4;
+        const INPUT_B: u64 = 6;
+        const EXPECTED: u64 = 12;
+        let output = lowest_common_multiple(INPUT_A, INPUT_B);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_1_case_1() {
+        const INPUT: &str = "
+        RL
+
+        AAA = (BBB, CCC)
+        BBB = (DDD, EEE)
+        CCC = (ZZZ, GGG)
+        DDD = (DDD, DDD)
+        EEE = (EEE, EEE)
+        GGG = (GGG, GGG)
+        ZZZ = (ZZZ, ZZZ)
+        ";
+        const EXPECTED: IntegerType = 2;
+        let (sequence, mapping) = parse_input(INPUT).unwrap();
+        let output = solve_part_1(&sequence, &mapping);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_1_case_2() {
+        const INPUT: &str = "
+        LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ZZZ = (ZZZ, ZZZ)
+        ";
+        const EXPECTED: IntegerType = 6;
+        let (sequence, mapping) = parse_input(INPUT).unwrap();
+        let output = solve_part_1(&sequence, &mapping);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        const INPUT: &str = "
+        LR
+
+        11A = (11B, XXX)
+        11B = (XXX, 11Z)
+        11Z = (11B, XXX)
+        22A = (22B, XXX)
+        22B = (22C, 22C)
+        22C = (22Z, 22Z)
+        22Z = (22B, 22B)
+        XXX = (XXX, XXX)
+        AAA = (ZZZ, ZZZ)
+        ZZZ = (ZZZ, ZZZ)
+        ";
+        const EXPECTED: Widened<IntegerType> = Widened::Narrow(6);
+        let output = Day08::solve_part_2(&parse_input(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_lowest_common_multiple_of_widens_on_overflow() {
+        // the primes either side of 2^32, whose lowest common multiple is past the largest u64.
+        const INPUT_A: u64 = 4294967291;
+        const INPUT_B: u64 = 4294967311;
+        let steps = [INPUT_A, INPUT_B];
+        let output = num::widen::<IntegerType>(
+            || lowest_common_multiple_of(&steps),
+            || lowest_common_multiple_of(&steps),
+        );
+        assert_eq!(output, Ok(Widened::Wide(18446744116659224501)));
+    }
+}
@@ -0,0 +1,237 @@
+use crate::num::{self, Integer, Overflow, Widened};
+use crate::parallel;
+use crate::stream::Streaming;
+use crate::{ParseError, Part, Solution, SolveError};
+use alloc::vec;
+use alloc::vec::Vec;
+
+type IntegerType = i32;
+type Wide = <IntegerType as Integer>::Wide;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+    type Parsed<'a> = Vec<Vec<Wide>>;
+    type Part1 = Widened<IntegerType>;
+    type Part2 = Widened<IntegerType>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_histories(input).map_err(|error| error.on_day(Self::DAY))
+    }
+
+    fn solve_part_1(histories: &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(num::widen(
+            || solve_part_1(histories),
+            || solve_part_1(histories),
+        )?)
+    }
+
+    fn solve_part_2(histories: &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(num::widen(
+            || solve_part_2(histories),
+            || solve_part_2(histories),
+        )?)
+    }
+}
+
+impl Streaming for Day09 {
+    type Line<'a> = Vec<Wide>;
+    type State = (Widened<IntegerType>, Widened<IntegerType>);
+
+    fn parse_line(line: &str) -> Result<Self::Line<'_>, ParseError> {
+        parse_history(line)
+    }
+
+    fn fold_line(
+        (part_1, part_2): &mut Self::State,
+        history: Self::Line<'_>,
+    ) -> Result<(), Overflow> {
+        let histories = vec![history];
+        *part_1 = num::widen(|| solve_part_1(&histories), || solve_part_1(&histories))
+            .and_then(|next| part_1.plus(next))
+            .map_err(|overflow| overflow.in_part(Part::One))?;
+        *part_2 = num::widen(|| solve_part_2(&histories), || solve_part_2(&histories))
+            .and_then(|next| part_2.plus(next))
+            .map_err(|overflow| overflow.in_part(Part::Two))?;
+        Ok(())
+    }
+
+    fn finish(state: Self::State) -> (Self::Part1, Self::Part2) {
+        state
+    }
+}
+
+fn solve_part_1<N: Integer>(histories: &[Vec<N::Wide>]) -> Result<N, Overflow> {
+    parallel::map(histories, |history| {
+        get_extrapolated_history(&num::narrow_all(history)?)
+    })
+    .into_iter()
+    .try_fold(N::ZERO, |total, value| total.try_add(value?))
+}
+
+fn solve_part_2<N: Integer>(histories: &[Vec<N::Wide>]) -> Result<N, Overflow> {
+    parallel::map(histories, |history| {
+        get_backwards_extrapolated_history(&num::narrow_all(history)?)
+    })
+    .into_iter()
+    .try_fold(N::ZERO, |total, value| total.try_add(value?))
+}
+
+fn parse_history<N: Integer>(input: &str) -> Result<Vec<N>, ParseError> {
+    input
+        .split_whitespace()
+        .map(|num| {
+            num.parse()
+                .map_err(|_| ParseError::new(input, num, "a number"))
+        })
+        .collect()
+}
+
+fn parse_histories<N: Integer>(input: &str) -> Result<Vec<Vec<N>>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| parse_history(line).map_err(|error| error.within(input, line)))
+        .collect()
+}
+
+fn get_histories_differences<N: Integer>(input: &[N]) -> Result<Vec<N>, Overflow> {
+    input
+        .windows(2)
+        .map(|window| window[1].try_sub(window[0]))
+        .collect()
+}
+
+fn get_extrapolated_history<N: Integer>(input: &[N]) -> Result<N, Overflow> {
+    if input.iter().all(|val| *val == N::ZERO) {
+        Ok(N::ZERO)
+    } else {
+        let next = get_extrapolated_history(&get_histories_differences(input)?)?;
+        input[input.len() - 1].try_add(next)
+    }
+}
+
+fn get_backwards_extrapolated_history<N: Integer>(input: &[N]) -> Result<N, Overflow> {
+    if input.iter().all(|val| *val == N::ZERO) {
+        Ok(N::ZERO)
+    } else {
+        let previous = get_backwards_extrapolated_history(&get_histories_differences(input)?)?;
+        input[0].try_sub(previous)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream;
+
+    #[test]
+    fn test_parse_history() {
+        const INPUT: &str = "0 3 6 9 12 15";
+        const EXPECTED: &[IntegerType] = &[0, 3, 6, 9, 12, 15];
+        let output = parse_history::<IntegerType>(INPUT).unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_histories() {
+        const INPUT: &str = "
+        0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45
+        ";
+        const EXPECTED: &[&[IntegerType]] = &[
+            &[0, 3, 6, 9, 12, 15],
+            &[1, 3, 6, 10, 15, 21],
+            &[10, 13, 16, 21, 30, 45],
+        ];
+        let output = parse_histories::<IntegerType>(INPUT).unwrap();
+        let output_refs: Vec<_> = output.iter().collect();
+        assert_eq!(&output_refs, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse_histories_invalid_number() {
+        const INPUT: &str = "
+        0 3 6 9 12 15
+        1 3 6 1O 15 21
+        ";
+        const EXPECTED: &str = "line 3, column 15: expected a number, found \"1O\"";
+        let output = parse_histories::<IntegerType>(INPUT)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_get_histories_differences() {
+        const INPUT: &[IntegerType] = &[1, 3, 6, 10, 15, 21];
+        const EXPECTED: &[IntegerType] = &[2, 3, 4, 5, 6];
+        let output = get_histories_differences(INPUT).unwrap();
+        assert_eq!(&output, EXPECTED);
+    }
+
+    #[test]
+    fn test_get_extrapolated_history() {
+        const INPUT: &[IntegerType] = &[10, 13, 16, 21, 30, 45];
+        const EXPECTED: IntegerType = 68;
+        let output = get_extrapolated_history(INPUT);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        const INPUT: &str = "
+        0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45
+        ";
+        const EXPECTED: IntegerType = 114;
+        let output = solve_part_1::<IntegerType>(&parse_histories(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_get_backwards_extrapolated_history() {
+        const INPUT: &[IntegerType] = &[10, 13, 16, 21, 30, 45];
+        const EXPECTED: IntegerType = 5;
+        let output = get_backwards_extrapolated_history(INPUT);
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        const INPUT: &str = "
+        0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45
+        ";
+        const EXPECTED: IntegerType = 2;
+        let output = solve_part_2::<IntegerType>(&parse_histories(INPUT).unwrap());
+        assert_eq!(output, Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_solve_widens_on_overflow() {
+        // the next value is 3000000000, past the largest i32, while every value given fits.
+        const INPUT: &str = "0 1000000000 2000000000\n";
+        let histories = parse_histories(INPUT).unwrap();
+        assert_eq!(
+            Day09::solve_part_1(&histories),
+            Ok(Widened::Wide(3000000000))
+        );
+        assert_eq!(
+            Day09::solve_part_2(&histories),
+            Ok(Widened::Narrow(-1000000000))
+        );
+        let output = stream::solve::<Day09>(&mut INPUT.as_bytes()).unwrap();
+        assert_eq!(
+            output,
+            (Widened::Wide(3000000000), Widened::Narrow(-1000000000))
+        );
+        let output = num::narrow_all::<IntegerType>(&parse_histories(INPUT).unwrap()[0]);
+        assert_eq!(output.map(|history| history.len()), Ok(3));
+    }
+}
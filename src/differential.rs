@@ -0,0 +1,230 @@
+//! Differential testing: days with more than one implementation are run side by side on
+//! generated inputs, and any input they give different answers for is reported.
+
+use std::fmt;
+use std::ops::Range;
+use std::panic;
+
+use crate::days::{
+    day05::{Day05, Day05PerSeed},
+    day06::{Day06, Day06ClosedForm},
+    day08::{Day08, Day08Walk},
+};
+use crate::generate;
+use crate::parallel;
+use crate::registry::Registration;
+use crate::Part;
+
+/// Every day with more than one implementation, in day order.
+pub const COMPARISONS: &[Comparison] = &[
+    Comparison {
+        day: 5,
+        size: 20,
+        implementations: &[
+            ("range splitting", Registration::new::<Day05>()),
+            ("per seed", Registration::new::<Day05PerSeed>()),
+        ],
+    },
+    Comparison {
+        day: 6,
+        size: 3,
+        implementations: &[
+            // part 2 scans every time, which grows tenfold with each digit of each race.
+            ("scan", Registration::new::<Day06>()),
+            ("closed form", Registration::new::<Day06ClosedForm>()),
+        ],
+    },
+    Comparison {
+        day: 8,
+        size: 20,
+        implementations: &[
+            ("cycle arithmetic", Registration::new::<Day08>()),
+            ("step by step", Registration::new::<Day08Walk>()),
+        ],
+    },
+];
+
+/// Implementations of the same day that should always agree, each with a name to report it by.
+pub struct Comparison {
+    pub day: u8,
+    /// The size of input to compare on by default, small enough for the slowest implementation.
+    pub size: usize,
+    pub implementations: &'static [(&'static str, Registration)],
+}
+
+impl Comparison {
+    /// Runs every implementation on the inputs generated from each of the seeds, stopping at the
+    /// first input they disagree on.
+    pub fn check(&self, seeds: Range<u64>, size: usize) -> Result<(), Disagreement> {
+        for seed in seeds {
+            let input = generate::generate(self.day, seed, size)
+                .unwrap_or_else(|| panic!("day {} has no input generator", self.day));
+            if let Some((part, answers)) = self.compare(&input) {
+                return Err(Disagreement {
+                    day: self.day,
+                    part,
+                    seed,
+                    size,
+                    input,
+                    answers,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The first part the implementations disagree on for `input`, with what each one gave.
+    pub fn compare(&self, input: &str) -> Option<(Part, Vec<(&'static str, Outcome)>)> {
+        compare(self.implementations, input)
+    }
+}
+
+/// What an implementation gave for a part of an input.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Answer(String),
+    /// The input couldn't be parsed, which every implementation should agree on.
+    Error(String),
+    /// The implementation panicked with this message.
+    Panic(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{answer}"),
+            Outcome::Error(error) => write!(f, "error: {error}"),
+            Outcome::Panic(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+/// The first part that `implementations` disagree on for `input`, with what each one gave. An
+/// implementation that panics never agrees, so a single implementation can be checked for
+/// panics too.
+pub fn compare(
+    implementations: &[(&'static str, Registration)],
+    input: &str,
+) -> Option<(Part, Vec<(&'static str, Outcome)>)> {
+    Part::ALL.iter().find_map(|part| {
+        let outcomes: Vec<_> = implementations
+            .iter()
+            .map(|(name, registration)| (*name, solve(registration, input, *part)))
+            .collect();
+        let agree = outcomes.windows(2).all(|pair| pair[0].1 == pair[1].1)
+            && !outcomes
+                .iter()
+                .any(|(_, outcome)| matches!(outcome, Outcome::Panic(_)));
+        (!agree).then_some((*part, outcomes))
+    })
+}
+
+fn solve(registration: &Registration, input: &str, part: Part) -> Outcome {
+    match panic::catch_unwind(|| registration.solve(input, &[part])) {
+        Ok(Ok(mut solved)) => Outcome::Answer(solved.answers.remove(0).value),
+        Ok(Err(error)) => Outcome::Error(error.to_string()),
+        Err(payload) => Outcome::Panic(parallel::panic_message(&*payload)),
+    }
+}
+
+/// Finds the comparison for the given day.
+pub fn find(day: u8) -> Option<&'static Comparison> {
+    COMPARISONS.iter().find(|comparison| comparison.day == day)
+}
+
+/// An input that implementations of the same day gave different answers for, or panicked on.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub day: u8,
+    pub part: Part,
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    /// The name of each implementation and what it gave.
+    pub answers: Vec<(&'static str, Outcome)>,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Day {} part {} disagrees on the input from seed {} with size {}:",
+            self.day, self.part, self.seed, self.size
+        )?;
+        for (name, answer) in &self.answers {
+            writeln!(f, "    {name} => {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day09::Day09;
+    use crate::num::Widened;
+    use crate::{ParseError, Solution, SolveError};
+
+    /// Gets part 2 of day 9 wrong whenever the first history starts with an even number.
+    struct Wrong;
+
+    impl Solution for Wrong {
+        const DAY: u8 = 9;
+        const TITLE: &'static str = Day09::TITLE;
+        type Parsed<'a> = <Day09 as Solution>::Parsed<'a>;
+        type Part1 = <Day09 as Solution>::Part1;
+        type Part2 = <Day09 as Solution>::Part2;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            Day09::parse(input)
+        }
+
+        fn solve_part_1(histories: &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+            Day09::solve_part_1(histories)
+        }
+
+        fn solve_part_2(histories: &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+            let wrong = Widened::Narrow((histories[0][0] % 2 == 0).into());
+            Ok(Day09::solve_part_2(histories)?.plus(wrong)?)
+        }
+    }
+
+    #[test]
+    fn test_comparisons_agree() {
+        for comparison in COMPARISONS {
+            let output = comparison.check(0..20, comparison.size);
+            assert!(output.is_ok(), "{}", output.unwrap_err());
+        }
+    }
+
+    #[test]
+    fn test_check_reports_first_disagreement() {
+        const COMPARISON: Comparison = Comparison {
+            day: 9,
+            size: 3,
+            implementations: &[
+                ("right", Registration::new::<Day09>()),
+                ("wrong", Registration::new::<Wrong>()),
+            ],
+        };
+        let even = |seed| {
+            let input = generate::generate(9, seed, 3).unwrap();
+            Day09::parse(&input).unwrap()[0][0] % 2 == 0
+        };
+        let expected = (0..100).find(|seed| even(*seed)).unwrap();
+        let output = COMPARISON.check(0..100, 3).unwrap_err();
+        assert_eq!((output.seed, output.part), (expected, Part::Two));
+        let answers: Vec<i64> = output
+            .answers
+            .iter()
+            .map(|(_, outcome)| outcome.to_string().parse().unwrap())
+            .collect();
+        assert_eq!(answers[1], answers[0] + 1);
+    }
+
+    #[test]
+    fn test_compare_parse_error() {
+        let output = find(5).unwrap().compare("seeds: x");
+        assert_eq!(output, None);
+    }
+}
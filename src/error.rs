@@ -0,0 +1,194 @@
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// An error produced when a puzzle input doesn't match the format a day expects.
+///
+/// Errors are created relative to the text being parsed, and then moved into the coordinates of
+/// the enclosing text with [`ParseError::within`] as they are returned up through the parsers,
+/// so that the final line and column refer to the whole input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    day: Option<u8>,
+    line: usize,
+    column: usize,
+    expected: &'static str,
+    found: String,
+}
+
+impl ParseError {
+    /// Creates an error for `fragment`, which must be a slice of `text`.
+    pub fn new(text: &str, fragment: &str, expected: &'static str) -> Self {
+        let (line, column) = locate(text, fragment);
+        Self {
+            day: None,
+            line,
+            column,
+            expected,
+            found: fragment.lines().next().unwrap_or_default().to_string(),
+        }
+    }
+
+    /// Creates an error for the end of `text`, for when more was expected.
+    pub fn at_end(text: &str, expected: &'static str) -> Self {
+        Self::new(text, &text[text.len()..], expected)
+    }
+
+    /// Moves an error produced while parsing `inner`, which must be a slice of `outer`, to be
+    /// relative to `outer`.
+    pub fn within(self, outer: &str, inner: &str) -> Self {
+        let (line, column) = locate(outer, inner);
+        Self {
+            line: line + self.line - 1,
+            column: if self.line == 1 {
+                column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+
+    /// Moves an error produced while parsing a single line to be on line `number` of the whole
+    /// input, for when the rest of the input isn't at hand.
+    pub fn on_line(self, number: usize) -> Self {
+        Self {
+            line: number + self.line - 1,
+            ..self
+        }
+    }
+
+    /// Records the day whose input failed to parse.
+    pub fn on_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// The line the error occurred on, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column the error occurred at, in characters and starting at 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// The text found where the error occurred, up to the end of its line.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "found end of line")
+        } else {
+            write!(f, "found {:?}", self.found)
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Finds the 1-based line and column that `fragment` starts at within `text`.
+fn locate(text: &str, fragment: &str) -> (usize, usize) {
+    let offset = (fragment.as_ptr() as usize)
+        .saturating_sub(text.as_ptr() as usize)
+        .min(text.len());
+    let before = &text[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .count()
+        + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        const INPUT: &str = "Game 1: 3 blue\nGame 2: 4 purple";
+        let output = ParseError::new(INPUT, &INPUT[25..], "a colour");
+        assert_eq!(output.line(), 2);
+        assert_eq!(output.column(), 11);
+        assert_eq!(output.found(), "purple");
+    }
+
+    #[test]
+    fn test_at_end() {
+        const INPUT: &str = "seeds: 1 2\n50 98";
+        let output = ParseError::at_end(INPUT, "three numbers");
+        assert_eq!(output.line(), 2);
+        assert_eq!(output.column(), 6);
+        assert_eq!(output.found(), "");
+    }
+
+    #[test]
+    fn test_within_first_line() {
+        const INPUT: &str = "Game 1: 3 blue, 4 purple";
+        let inner = &INPUT[16..];
+        let output = ParseError::new(inner, &inner[2..], "a colour").within(INPUT, inner);
+        assert_eq!(output.line(), 1);
+        assert_eq!(output.column(), 19);
+    }
+
+    #[test]
+    fn test_within_later_line() {
+        const INPUT: &str = "seeds: 1 2\n\nseed-to-soil map:\n50 98 x";
+        let inner = &INPUT[12..];
+        let output = ParseError::new(inner, &inner[24..], "a number").within(INPUT, inner);
+        assert_eq!(output.line(), 4);
+        assert_eq!(output.column(), 7);
+    }
+
+    #[test]
+    fn test_on_line() {
+        const INPUT: &str = "Game 7: 3 blue, 4 purple";
+        let output = ParseError::new(INPUT, &INPUT[18..], "a colour").on_line(7);
+        assert_eq!(output.line(), 7);
+        assert_eq!(output.column(), 19);
+    }
+
+    #[test]
+    fn test_display() {
+        const INPUT: &str = "Game 1: 3 blue, 4 purple";
+        const EXPECTED: &str = "day 2, line 1, column 19: expected a colour, found \"purple\"";
+        let output = ParseError::new(INPUT, &INPUT[18..], "a colour")
+            .on_day(2)
+            .to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_display_end_of_line() {
+        const INPUT: &str = "50 98";
+        const EXPECTED: &str = "line 1, column 6: expected three numbers, found end of line";
+        let output = ParseError::at_end(INPUT, "three numbers").to_string();
+        assert_eq!(output, EXPECTED);
+    }
+}
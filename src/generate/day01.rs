@@ -0,0 +1,26 @@
+use std::io::{self, Write};
+
+use super::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` lines of letters, digits and spelled out digits, each with at least one digit.
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        let mut tokens: Vec<String> = (0..rng.range(1..=8))
+            .map(|_| match rng.range(0..=2) {
+                0 => rng.range(1..=9).to_string(),
+                1 => WORDS[rng.index(WORDS.len())].to_string(),
+                _ => (0..rng.range(1..=5))
+                    .map(|_| (b'a' + rng.range(0..=25) as u8) as char)
+                    .collect(),
+            })
+            .collect();
+        let index = rng.index(tokens.len() + 1);
+        tokens.insert(index, rng.range(1..=9).to_string());
+        writeln!(out, "{}", tokens.concat())?;
+    }
+    Ok(())
+}
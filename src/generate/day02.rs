@@ -0,0 +1,25 @@
+use std::io::{self, Write};
+
+use super::Rng;
+
+const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+/// `size` games of up to six draws, each showing every colour at most once.
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for id in 1..=size {
+        let draws: Vec<String> = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colours = COLOURS;
+                rng.shuffle(&mut colours);
+                let shown = rng.range(1..=3) as usize;
+                colours[..shown]
+                    .iter()
+                    .map(|colour| format!("{} {colour}", rng.range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        writeln!(out, "Game {id}: {}", draws.join("; "))?;
+    }
+    Ok(())
+}
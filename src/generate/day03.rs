@@ -0,0 +1,37 @@
+use std::io::{self, Write};
+
+use super::Rng;
+
+const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+/// The width of the real schematics.
+const MAX_WIDTH: usize = 140;
+
+/// A schematic of `size` rows of numbers and symbols, as wide as it is tall up to the width of
+/// the real ones. Numbers never run into each other.
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let width = size.min(MAX_WIDTH);
+    for _ in 0..size {
+        let mut row = Vec::with_capacity(width);
+        while row.len() < width {
+            let remaining = width - row.len();
+            if rng.chance(15) {
+                let digits = rng.range(1..=3.min(remaining as u64));
+                row.push(b'0' + rng.range(1..=9) as u8);
+                for _ in 1..digits {
+                    row.push(b'0' + rng.range(0..=9) as u8);
+                }
+                if row.len() < width {
+                    row.push(if rng.chance(30) { b'*' } else { b'.' });
+                }
+            } else if rng.chance(8) {
+                row.push(*rng.choose(SYMBOLS));
+            } else {
+                row.push(b'.');
+            }
+        }
+        row.push(b'\n');
+        out.write_all(&row)?;
+    }
+    Ok(())
+}
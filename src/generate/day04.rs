@@ -0,0 +1,44 @@
+use std::io::{self, Write};
+
+use super::Rng;
+
+const WINNING: usize = 10;
+const HAVE: usize = 25;
+
+/// `size` cards. Matches are kept rare, as each one copies later cards, and a card never wins
+/// copies of cards past the end of the table.
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let width = size.to_string().len();
+    for id in 1..=size {
+        let mut numbers: Vec<u64> = (1..=99).collect();
+        rng.shuffle(&mut numbers);
+        let (winning, others) = numbers.split_at(WINNING);
+        let matches = match rng.range(1..=100) {
+            1..=60 => 0,
+            61..=82 => 1,
+            83..=92 => 2,
+            93..=97 => 3,
+            _ => rng.range(4..=WINNING as u64) as usize,
+        }
+        .min(size - id);
+        let mut have: Vec<u64> = winning[..matches].to_vec();
+        have.extend_from_slice(&others[..HAVE - matches]);
+        rng.shuffle(&mut have);
+        let mut winning = winning.to_vec();
+        rng.shuffle(&mut winning);
+        let columns = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(|number| format!("{number:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        writeln!(
+            out,
+            "Card {id:>width$}: {} | {}",
+            columns(&winning),
+            columns(&have)
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::io::{self, Write};
+
+use super::Rng;
+
+/// Keeps every value clear of the top of `u32`, as the real inputs do.
+const LIMIT: u64 = 4_000_000_000;
+/// How far values can reach for each unit of size, so that small almanacs have small ranges
+/// which can be checked seed by seed, and are more likely to overlap.
+const SPREAD: u64 = 1000;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// An almanac with `size` seed ranges and up to `size` sections in each map. The sections of a
+/// map never overlap each other.
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let limit = (size as u64).saturating_mul(SPREAD).min(LIMIT);
+    let seeds: Vec<String> = (0..size)
+        .map(|_| {
+            let start = rng.range(0..=limit - 1);
+            let length = rng.range(1..=(limit - start).min(limit / 10).max(1));
+            format!("{start} {length}")
+        })
+        .collect();
+    writeln!(out, "seeds: {}", seeds.join(" "))?;
+    for name in MAPS {
+        let mut bounds: Vec<u64> = (0..size * 2).map(|_| rng.range(0..=limit)).collect();
+        bounds.sort_unstable();
+        bounds.dedup();
+        let mut sections: Vec<String> = bounds
+            .chunks_exact(2)
+            .map(|pair| {
+                let (source, length) = (pair[0], pair[1] - pair[0]);
+                let destination = rng.range(0..=limit - length);
+                format!("{destination} {source} {length}")
+            })
+            .collect();
+        rng.shuffle(&mut sections);
+        write!(out, "\n{name} map:\n")?;
+        for section in sections {
+            writeln!(out, "{section}")?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use std::io::{self, Write};
+
+use super::Rng;
+
+/// Part 2 reads every time as one number, so more races make it grow tenfold per digit.
+const MAX_RACES: usize = 4;
+
+/// Up to four races, where `size` is the number of races. Every record can be beaten.
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let races: Vec<(u64, u64)> = (0..size.min(MAX_RACES))
+        .map(|_| {
+            let time = rng.range(7..=99);
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.range(0..=best - 1))
+        })
+        .collect();
+    let width = races
+        .iter()
+        .map(|(_, record)| record.to_string().len())
+        .max()
+        .unwrap_or(0)
+        + 3;
+    let mut times = "Time:    ".to_string();
+    let mut distances = "Distance:".to_string();
+    for (time, record) in races {
+        times.push_str(&format!("{time:>width$}"));
+        distances.push_str(&format!("{record:>width$}"));
+    }
+    write!(out, "{times}\n{distances}\n")
+}
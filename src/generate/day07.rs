@@ -0,0 +1,20 @@
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+use super::Rng;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// `size` distinct hands with their bids. There are only so many distinct hands, so very large
+/// sizes are capped.
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let size = size.min(CARDS.len().pow(5));
+    let mut seen = HashSet::new();
+    while seen.len() < size {
+        let hand: String = (0..5).map(|_| *rng.choose(CARDS) as char).collect();
+        if seen.insert(hand.clone()) {
+            writeln!(out, "{hand} {}", rng.range(1..=1000))?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+use super::Rng;
+
+const LABEL: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+const MAX_GHOSTS: usize = 6;
+/// Stays well short of the labels there are for nodes along a path.
+const MAX_NODES: usize = 20_000;
+
+/// A network of roughly `size` nodes, up to 20,000. Each ghost follows its own loop through the network,
+/// which is a whole number of passes through the directions long, and whose end node leads
+/// where its start node does. That is what makes part 2 a lowest common multiple, as it is in
+/// the real inputs. The first ghost starts at `AAA` and ends at `ZZZ` for part 1.
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let size = size.min(MAX_NODES);
+    let ghosts = (size / 20).clamp(1, MAX_GHOSTS);
+    let budget = (size / ghosts).max(1) as u64;
+    let directions: Vec<u8> = (0..rng.range(1..=budget.min(300)))
+        .map(|_| if rng.chance(50) { b'L' } else { b'R' })
+        .collect();
+    let period = directions.len() as u64;
+    let mut labels = Labels::default();
+    let mut paths = Vec::new();
+    for ghost in 0..ghosts {
+        let (start, end) = if ghost == 0 {
+            labels.take("AAA".to_string());
+            labels.take("ZZZ".to_string());
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            let prefix = labels.fresh_prefix(rng);
+            (format!("{prefix}A"), format!("{prefix}Z"))
+        };
+        let steps = period * rng.range(1..=(budget / period).max(1));
+        let mut path = vec![start];
+        path.extend((1..steps).map(|_| labels.fresh(rng)));
+        path.push(end);
+        paths.push(path);
+    }
+    let all: Vec<String> = paths.iter().flatten().cloned().collect();
+    let mut nodes = Vec::new();
+    for path in &paths {
+        let mut children = Vec::new();
+        for (step, pair) in path.windows(2).enumerate() {
+            let other = rng.choose(&all).clone();
+            children.push(match directions[step % directions.len()] {
+                b'L' => (pair[1].clone(), other),
+                _ => (other, pair[1].clone()),
+            });
+        }
+        // leaving the end node goes the same way as leaving the start node did.
+        children.push(children[0].clone());
+        for (label, (left, right)) in path.iter().zip(children) {
+            nodes.push(format!("{label} = ({left}, {right})"));
+        }
+    }
+    rng.shuffle(&mut nodes);
+    out.write_all(&directions)?;
+    write!(out, "\n\n{}\n", nodes.join("\n"))
+}
+
+#[derive(Default)]
+struct Labels {
+    used: HashSet<String>,
+}
+
+impl Labels {
+    fn take(&mut self, label: String) -> bool {
+        self.used.insert(label)
+    }
+
+    /// A label for a node along a path, which must not end like a start or end node.
+    fn fresh(&mut self, rng: &mut Rng) -> String {
+        loop {
+            let mut label: String = (0..2).map(|_| *rng.choose(LABEL) as char).collect();
+            label.push(*rng.choose(&LABEL[1..25]) as char);
+            if self.take(label.clone()) {
+                return label;
+            }
+        }
+    }
+
+    /// Two characters that are free to start both a start and an end node.
+    fn fresh_prefix(&mut self, rng: &mut Rng) -> String {
+        loop {
+            let prefix: String = (0..2).map(|_| *rng.choose(LABEL) as char).collect();
+            let (start, end) = (format!("{prefix}A"), format!("{prefix}Z"));
+            if !self.used.contains(&start) && !self.used.contains(&end) {
+                self.take(start);
+                self.take(end);
+                return prefix;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day08::Day08;
+    use crate::generate::generate;
+    use crate::Solution;
+
+    #[test]
+    fn test_generate_loops() {
+        for seed in 0..10 {
+            let input = generate(8, seed, 500).unwrap();
+            let parsed = Day08::parse(&input).unwrap();
+            let steps = Day08::solve_part_1(&parsed).unwrap().wide();
+            // ghosts only line up at a common multiple of their loops.
+            assert_eq!(
+                Day08::solve_part_2(&parsed).unwrap().wide() % steps,
+                0,
+                "seed {seed}"
+            );
+        }
+    }
+}
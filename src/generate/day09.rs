@@ -0,0 +1,29 @@
+use std::io::{self, Write};
+
+use super::Rng;
+
+/// How large a coefficient can be for each power, so that values stay well within an `i32`.
+const COEFFICIENTS: [i64; 5] = [1000, 200, 30, 5, 1];
+
+/// `size` histories, each the values of a small polynomial, as the real inputs are.
+pub fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        let length = rng.range(6..=21) as i64;
+        let degree = rng.range(0..=COEFFICIENTS.len() as u64 - 1) as usize;
+        let coefficients: Vec<i64> = COEFFICIENTS[..=degree]
+            .iter()
+            .map(|&bound| rng.range(0..=2 * bound as u64) as i64 - bound)
+            .collect();
+        let values: Vec<String> = (0..length)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| value * x + coefficient)
+                    .to_string()
+            })
+            .collect();
+        writeln!(out, "{}", values.join(" "))?;
+    }
+    Ok(())
+}
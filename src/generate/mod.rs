@@ -0,0 +1,156 @@
+//! Random but valid puzzle inputs, for stress testing and benchmarking at sizes the real inputs
+//! don't reach.
+//!
+//! Every input is reproducible from its seed. What the size means depends on the day, but it is
+//! roughly the number of lines or records in the input.
+
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+
+/// Generates an input for a day, or `None` if the day has no generator.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let mut input = Vec::new();
+    write(day, seed, size, &mut input)?.expect("writing to a Vec can't fail");
+    Some(String::from_utf8(input).expect("inputs are generated as ASCII"))
+}
+
+/// Writes an input for a day to `out` as it is generated, so that inputs larger than memory
+/// can be piped straight into a solver. Returns `None` if the day has no generator.
+pub fn write(day: u8, seed: u64, size: usize, out: &mut dyn Write) -> Option<io::Result<()>> {
+    let generate = match day {
+        1 => day01::generate,
+        2 => day02::generate,
+        3 => day03::generate,
+        4 => day04::generate,
+        5 => day05::generate,
+        6 => day06::generate,
+        7 => day07::generate,
+        8 => day08::generate,
+        9 => day09::generate,
+        _ => return None,
+    };
+    Some(generate(&mut Rng::new(seed), size.max(1), out))
+}
+
+/// A SplitMix64 generator, which is small enough to keep here so that inputs only depend on
+/// the seed.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {start}..={end}");
+        let span = (end - start) as u128 + 1;
+        start + ((self.next_u64() as u128 * span) >> 64) as u64
+    }
+
+    /// An index into something of length `len`, which must not be zero.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    /// True with a probability of `percent` in 100.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range(1..=100) <= percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::{Registration, SOLUTIONS};
+    use crate::Part;
+
+    /// The registered days that have a generator, which a day started with `aoc new` doesn't.
+    fn generated() -> impl Iterator<Item = &'static Registration> {
+        SOLUTIONS
+            .iter()
+            .filter(|registration| write(registration.day(), 0, 1, &mut io::sink()).is_some())
+    }
+
+    #[test]
+    fn test_rng_range() {
+        let mut rng = Rng::new(1);
+        let output: Vec<_> = (0..1000).map(|_| rng.range(3..=5)).collect();
+        assert!(output.iter().all(|n| (3..=5).contains(n)));
+        assert!((3..=5).all(|n| output.contains(&n)));
+        assert_eq!(rng.range(7..=7), 7);
+    }
+
+    #[test]
+    fn test_rng_shuffle() {
+        let mut input: Vec<_> = (0..20).collect();
+        Rng::new(7).shuffle(&mut input);
+        let mut output = input.clone();
+        output.sort();
+        assert_ne!(input, output);
+        assert_eq!(output, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_generate_reproducible() {
+        for registration in generated() {
+            let day = registration.day();
+            let first = generate(day, 42, 10);
+            assert_eq!(first, generate(day, 42, 10), "day {day}");
+            assert_ne!(first, generate(day, 43, 10), "day {day}");
+        }
+    }
+
+    #[test]
+    fn test_generate_unknown_day() {
+        assert_eq!(generate(25, 0, 10), None);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for registration in generated() {
+            for (seed, size) in (0..4).zip([1, 2, 3, 50]) {
+                let input = generate(registration.day(), seed, size).unwrap();
+                let output = registration.solve(&input, &Part::ALL);
+                assert!(
+                    output.is_ok(),
+                    "day {} seed {seed} size {size}: {}\n{input}",
+                    registration.day(),
+                    output.unwrap_err()
+                );
+            }
+        }
+    }
+}
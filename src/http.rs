@@ -0,0 +1,385 @@
+//! Just enough of an HTTP client to talk to the puzzle site.
+//!
+//! Plain `http://` URLs are spoken to directly, which is what the tests use with a local stand-in
+//! server. The crate has no dependencies to speak TLS with, so `https://` requests are handed to
+//! `curl` instead.
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub enum HttpError {
+    InvalidUrl(String),
+    Io(io::Error),
+    InvalidResponse,
+    Curl(String),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::InvalidUrl(url) => write!(f, "Invalid URL {url}"),
+            HttpError::Io(error) => write!(f, "Request failed: {error}"),
+            HttpError::InvalidResponse => write!(f, "Request failed: invalid response"),
+            HttpError::Curl(message) => write!(f, "Request failed: curl: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<io::Error> for HttpError {
+    fn from(error: io::Error) -> Self {
+        HttpError::Io(error)
+    }
+}
+
+pub fn get(url: &str, headers: &[(&str, &str)]) -> Result<Response, HttpError> {
+    request("GET", url, headers, None)
+}
+
+/// Posts `form` URL-encoded, as a browser submitting a form would.
+pub fn post_form(
+    url: &str,
+    headers: &[(&str, &str)],
+    form: &[(&str, &str)],
+) -> Result<Response, HttpError> {
+    let body = form
+        .iter()
+        .map(|(name, value)| format!("{}={}", url_encode(name), url_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+    let mut headers = headers.to_vec();
+    headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+    request("POST", url, &headers, Some(&body))
+}
+
+fn url_encode(string: &str) -> String {
+    string
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, HttpError> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        let (authority, path) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, "/"),
+        };
+        if authority.is_empty() {
+            return Err(HttpError::InvalidUrl(url.to_string()));
+        }
+        request_plain(method, authority, path, headers, body)
+    } else if url.starts_with("https://") {
+        request_curl(method, url, headers, body)
+    } else {
+        Err(HttpError::InvalidUrl(url.to_string()))
+    }
+}
+
+fn request_plain(
+    method: &str,
+    authority: &str,
+    path: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, HttpError> {
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{authority}:80")
+    };
+    let mut stream = TcpStream::connect(address)?;
+    let mut request =
+        format!("{method} {path} HTTP/1.1\r\nHost: {authority}\r\nConnection: close\r\n");
+    for (name, value) in headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    if let Some(body) = body {
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(body.unwrap_or_default());
+    stream.write_all(request.as_bytes())?;
+    read_response(BufReader::new(stream))
+}
+
+fn read_response(mut reader: impl BufRead) -> Result<Response, HttpError> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let status = line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse().ok())
+        .ok_or(HttpError::InvalidResponse)?;
+    let mut chunked = false;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(HttpError::InvalidResponse);
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            chunked |= name.eq_ignore_ascii_case("transfer-encoding")
+                && value.trim().eq_ignore_ascii_case("chunked");
+        }
+    }
+    let mut body = vec![];
+    if chunked {
+        loop {
+            line.clear();
+            reader.read_line(&mut line)?;
+            let size = usize::from_str_radix(line.trim_end(), 16)
+                .map_err(|_| HttpError::InvalidResponse)?;
+            if size == 0 {
+                break;
+            }
+            let start = body.len();
+            body.resize(start + size, 0);
+            reader.read_exact(&mut body[start..])?;
+            line.clear();
+            reader.read_line(&mut line)?;
+        }
+    } else {
+        reader.read_to_end(&mut body)?;
+    }
+    let body = String::from_utf8(body).map_err(|_| HttpError::InvalidResponse)?;
+    Ok(Response { status, body })
+}
+
+/// Requests `url` with `curl`. The headers and body are written to its stdin as a config file
+/// rather than passed as arguments, as the arguments of every process can be read by anyone on
+/// the machine and the headers hold the session cookie.
+fn request_curl(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, HttpError> {
+    let mut command = Command::new("curl");
+    command.args(["--silent", "--show-error", "--request", method]);
+    command.args(["--write-out", "\n%{http_code}", "--config", "-"]);
+    let mut child = command
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| HttpError::Curl(error.to_string()))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(curl_config(headers, body).as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stderr);
+        return Err(HttpError::Curl(message.trim().to_string()));
+    }
+    let output = String::from_utf8(output.stdout).map_err(|_| HttpError::InvalidResponse)?;
+    let (body, status) = output.rsplit_once('\n').ok_or(HttpError::InvalidResponse)?;
+    let status = status.parse().map_err(|_| HttpError::InvalidResponse)?;
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+/// A `curl` config file giving the headers and body of a request. `data-raw` is used for the
+/// body, as `data-binary` would read a file if the body started with `@`.
+fn curl_config(headers: &[(&str, &str)], body: Option<&str>) -> String {
+    let mut config = String::new();
+    for (name, value) in headers {
+        config += &format!("header = {}\n", curl_quote(&format!("{name}: {value}")));
+    }
+    if let Some(body) = body {
+        config += &format!("data-raw = {}\n", curl_quote(body));
+    }
+    config
+}
+
+/// Quotes a value for a `curl` config file, escaping what would end the string or the line.
+fn curl_quote(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted += "\\\"",
+            '\\' => quoted += "\\\\",
+            '\n' => quoted += "\\n",
+            '\r' => quoted += "\\r",
+            '\t' => quoted += "\\t",
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// A local server standing in for the puzzle site in tests.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves each of `responses` to one connection in turn, as `(status, body)`, and returns
+    /// the base URL along with a handle that yields the requests that were received.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Stand-in\r\nContent-Length: {}\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let (url, server) = stand_in::serve(vec![(200, "0 3 6 9 12 15\n")]);
+        let output = get(
+            &format!("{url}/2023/day/9/input"),
+            &[("Cookie", "session=abc")],
+        )
+        .unwrap();
+        let requests = server.join().unwrap();
+        assert_eq!(
+            output,
+            Response {
+                status: 200,
+                body: "0 3 6 9 12 15\n".to_string()
+            }
+        );
+        assert!(requests[0].starts_with("GET /2023/day/9/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn test_post_form() {
+        let (url, server) = stand_in::serve(vec![(200, "ok")]);
+        let output = post_form(
+            &format!("{url}/2023/day/1/answer"),
+            &[],
+            &[("level", "1"), ("answer", "-5 & 6")],
+        )
+        .unwrap();
+        let requests = server.join().unwrap();
+        assert_eq!(output.status, 200);
+        assert!(requests[0].starts_with("POST /2023/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nContent-Type: application/x-www-form-urlencoded\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=-5%20%26%206"));
+    }
+
+    #[test]
+    fn test_read_response_chunked() {
+        const INPUT: &str =
+            "HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nNot \r\n5\r\nfound\r\n0\r\n\r\n";
+        let expected = Response {
+            status: 404,
+            body: "Not found".to_string(),
+        };
+        let output = read_response(INPUT.as_bytes()).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_request_curl() {
+        if Command::new("curl").arg("--version").output().is_err() {
+            return; // nothing to test without curl installed.
+        }
+        let (url, server) = stand_in::serve(vec![(201, "ok")]);
+        let output = request_curl(
+            "POST",
+            &format!("{url}/2023/day/1/answer"),
+            &[("Cookie", "session=abc")],
+            Some("@level=1"),
+        )
+        .unwrap();
+        let requests = server.join().unwrap();
+        assert_eq!(
+            output,
+            Response {
+                status: 201,
+                body: "ok".to_string()
+            }
+        );
+        assert!(requests[0].starts_with("POST /2023/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\n@level=1"));
+    }
+
+    #[test]
+    fn test_curl_config() {
+        const INPUT_HEADERS: &[(&str, &str)] = &[
+            ("Cookie", "session=abc"),
+            ("User-Agent", "aoc \"2023\" \\ test"),
+        ];
+        const INPUT_BODY: &str = "@level=1&answer=a\nb";
+        const EXPECTED: &str = "\
+header = \"Cookie: session=abc\"
+header = \"User-Agent: aoc \\\"2023\\\" \\\\ test\"
+data-raw = \"@level=1&answer=a\\nb\"
+";
+        let output = curl_config(INPUT_HEADERS, Some(INPUT_BODY));
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_get_invalid_url() {
+        const INPUT: &str = "ftp://adventofcode.com/2023";
+        let output = get(INPUT, &[]).unwrap_err().to_string();
+        assert_eq!(output, "Invalid URL ftp://adventofcode.com/2023");
+    }
+}
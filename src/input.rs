@@ -0,0 +1,225 @@
+use std::borrow::Cow;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the directory that inputs are read from.
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+/// Directory that inputs are read from when [`INPUT_DIR_VARIABLE`] is not set.
+pub const DEFAULT_INPUT_DIR: &str = "data";
+
+/// Where the input for a day should be read from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    /// The day's file in the input directory, or the embedded input if the
+    /// `embed-inputs` feature is enabled and no input directory has been set.
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: InputSource,
+    error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.source {
+            InputSource::Stdin => write!(f, "Failed to read input from stdin: {}", self.error),
+            _ => write!(f, "Failed to read input: {}", self.error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Loads the input for the given day.
+pub fn load(day: u8, source: &InputSource) -> Result<Cow<'static, str>, InputError> {
+    let result = match source {
+        InputSource::Default => match embedded(day) {
+            Some(input) if env::var_os(INPUT_DIR_VARIABLE).is_none() => {
+                return Ok(Cow::Borrowed(input))
+            }
+            _ => read_file(&path_in(&input_dir(), day)),
+        },
+        InputSource::Path(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map(|_| input)
+        }
+    };
+    result.map(Cow::Owned).map_err(|error| InputError {
+        source: source.clone(),
+        error,
+    })
+}
+
+/// Opens the input for the given day to be read as it is needed, rather than loaded all at once.
+pub fn open(day: u8, source: &InputSource) -> Result<Box<dyn BufRead>, InputError> {
+    let result = match source {
+        InputSource::Default => match embedded(day) {
+            Some(input) if env::var_os(INPUT_DIR_VARIABLE).is_none() => {
+                return Ok(Box::new(input.as_bytes()))
+            }
+            _ => open_file(&path_in(&input_dir(), day)),
+        },
+        InputSource::Path(path) => open_file(path),
+        InputSource::Stdin => Ok(Box::new(io::stdin().lock()) as Box<dyn BufRead>),
+    };
+    result.map_err(|error| InputError {
+        source: source.clone(),
+        error,
+    })
+}
+
+/// The directory inputs are read from, taken from [`INPUT_DIR_VARIABLE`] if it is set.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VARIABLE)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// The path of the input for a day within the given directory.
+pub fn path_in(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{day:02}.txt"))
+}
+
+/// The directory that holds any number of named inputs for a day, such as one for each account,
+/// within the given directory.
+pub fn named_dir_in(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{day:02}"))
+}
+
+/// The named inputs for a day in the given directory, which are the `.txt` files in
+/// [`named_dir_in`] named after their file stems, in order of name. A day without the directory
+/// has none.
+pub fn named_inputs(dir: &Path, day: u8) -> io::Result<Vec<(String, PathBuf)>> {
+    let dir = named_dir_in(dir, day);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => {
+            return Err(io::Error::new(
+                error.kind(),
+                format!("{}: {error}", dir.display()),
+            ))
+        }
+    };
+    let mut inputs = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                inputs.push((name.to_string(), path.clone()));
+            }
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {error}", path.display())))
+}
+
+fn open_file(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    fs::File::open(path)
+        .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {error}", path.display())))
+}
+
+#[cfg(feature = "embed-inputs")]
+fn embedded(day: u8) -> Option<&'static str> {
+    match day {
+        1 => Some(include_str!("../data/day01.txt")),
+        2 => Some(include_str!("../data/day02.txt")),
+        3 => Some(include_str!("../data/day03.txt")),
+        4 => Some(include_str!("../data/day04.txt")),
+        5 => Some(include_str!("../data/day05.txt")),
+        6 => Some(include_str!("../data/day06.txt")),
+        7 => Some(include_str!("../data/day07.txt")),
+        8 => Some(include_str!("../data/day08.txt")),
+        9 => Some(include_str!("../data/day09.txt")),
+        _ => None,
+    }
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+fn embedded(_: u8) -> Option<&'static str> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_in() {
+        const INPUT_DAY: u8 = 5;
+        let expected = PathBuf::from("inputs/day05.txt");
+        let output = path_in(Path::new("inputs"), INPUT_DAY);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_named_inputs() {
+        let dir = env::temp_dir().join(format!("aoc-2023-named-{}", std::process::id()));
+        let day_dir = named_dir_in(&dir, 8);
+        fs::create_dir_all(&day_dir).unwrap();
+        for file in ["bob.txt", "alice.txt", "notes.md"] {
+            fs::write(day_dir.join(file), "").unwrap();
+        }
+        let output = named_inputs(&dir, 8).unwrap();
+        let missing = named_inputs(&dir, 9).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        let expected = vec![
+            ("alice".to_string(), day_dir.join("alice.txt")),
+            ("bob".to_string(), day_dir.join("bob.txt")),
+        ];
+        assert_eq!(output, expected);
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn test_load_from_path() {
+        const INPUT: &str = "0 3 6 9 12 15\n";
+        let path = env::temp_dir().join(format!("aoc-2023-input-{}.txt", std::process::id()));
+        fs::write(&path, INPUT).unwrap();
+        let output = load(9, &InputSource::Path(path.clone()));
+        fs::remove_file(&path).unwrap();
+        assert_eq!(output.unwrap(), INPUT);
+    }
+
+    #[test]
+    fn test_open_from_path() {
+        const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n";
+        let path = env::temp_dir().join(format!("aoc-2023-open-{}.txt", std::process::id()));
+        fs::write(&path, INPUT).unwrap();
+        let output: Vec<_> = open(9, &InputSource::Path(path.clone()))
+            .unwrap()
+            .lines()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(output, ["0 3 6 9 12 15", "1 3 6 10 15 21"]);
+    }
+
+    #[test]
+    fn test_load_from_missing_path() {
+        let input = InputSource::Path(PathBuf::from("does/not/exist.txt"));
+        let output = load(9, &input).unwrap_err();
+        assert!(output
+            .to_string()
+            .starts_with("Failed to read input: does/not/exist.txt"));
+    }
+}
@@ -0,0 +1,343 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::ParseError;
+
+/// A minimal JSON value, enough to write results out and read back files that were written.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(BTreeMap<String, Value>),
+}
+
+impl Value {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser { text, position: 0 };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.position == text.len() {
+            Ok(value)
+        } else {
+            Err(parser.error("the end of the document"))
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(object) => object.get(key),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&BTreeMap<String, Value>> {
+        match self {
+            Value::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(bool) => write!(f, "{bool}"),
+            Value::Number(number) if number.is_finite() => write!(f, "{number}"),
+            Value::Number(_) => write!(f, "null"),
+            Value::String(string) => write_string(f, string),
+            Value::Array(array) => {
+                write!(f, "[")?;
+                for (index, value) in array.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(object) => {
+                write!(f, "{{")?;
+                for (index, (key, value)) in object.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(string: &str) -> Self {
+        Value::String(string.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(string: String) -> Self {
+        Value::String(string)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(number: f64) -> Self {
+        Value::Number(number)
+    }
+}
+
+impl<const N: usize> From<[(&str, Value); N]> for Value {
+    fn from(entries: [(&str, Value); N]) -> Self {
+        Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        )
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, string: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in string.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, expected: &'static str) -> ParseError {
+        ParseError::new(self.text, &self.text[self.position..], expected)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn expect(&mut self, token: &str, expected: &'static str) -> Result<(), ParseError> {
+        if self.text[self.position..].starts_with(token) {
+            self.position += token.len();
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('n') => self.expect("null", "a value").map(|_| Value::Null),
+            Some('t') => self.expect("true", "a value").map(|_| Value::Bool(true)),
+            Some('f') => self.expect("false", "a value").map(|_| Value::Bool(false)),
+            Some('"') => self.parse_string().map(Value::String),
+            Some('[') => self.parse_array(),
+            Some('{') => self.parse_object(),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            _ => Err(self.error("a value")),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Value, ParseError> {
+        let start = self.position;
+        let rest = &self.text[start..];
+        let length = rest
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')))
+            .unwrap_or(rest.len());
+        let number = rest[..length].parse().map_err(|_| self.error("a number"))?;
+        self.position += length;
+        Ok(Value::Number(number))
+    }
+
+    fn parse_string(&mut self) -> Result<String, ParseError> {
+        self.expect("\"", "a string")?;
+        let mut string = String::new();
+        let mut chars = self.text[self.position..].char_indices();
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.position += index + 1;
+                    return Ok(string);
+                }
+                '\\' => {
+                    let escaped = match chars.next() {
+                        Some((_, '"')) => '"',
+                        Some((_, '\\')) => '\\',
+                        Some((_, '/')) => '/',
+                        Some((_, 'n')) => '\n',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 't')) => '\t',
+                        Some((_, 'b')) => '\u{8}',
+                        Some((_, 'f')) => '\u{c}',
+                        Some((_, 'u')) => {
+                            let code: String = (0..4)
+                                .filter_map(|_| chars.next())
+                                .map(|(_, c)| c)
+                                .collect();
+                            u32::from_str_radix(&code, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| {
+                                    self.position += index;
+                                    self.error("a unicode escape")
+                                })?
+                        }
+                        _ => {
+                            self.position += index;
+                            return Err(self.error("an escape sequence"));
+                        }
+                    };
+                    string.push(escaped);
+                }
+                c => string.push(c),
+            }
+        }
+        self.position = self.text.len();
+        Err(self.error("\"\\\"\" at the end of the string"))
+    }
+
+    fn parse_array(&mut self) -> Result<Value, ParseError> {
+        self.expect("[", "an array")?;
+        let mut array = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(Value::Array(array));
+        }
+        loop {
+            array.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(']') => {
+                    self.position += 1;
+                    return Ok(Value::Array(array));
+                }
+                _ => return Err(self.error("\",\" or \"]\"")),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Value, ParseError> {
+        self.expect("{", "an object")?;
+        let mut object = BTreeMap::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(Value::Object(object));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(":", "\":\" after the key")?;
+            object.insert(key, self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some('}') => {
+                    self.position += 1;
+                    return Ok(Value::Object(object));
+                }
+                _ => return Err(self.error("\",\" or \"}\"")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let input = Value::from([
+            ("answer", Value::from("54331")),
+            ("day", Value::from(1.0)),
+            ("note", Value::from("tab\there \"quoted\"")),
+            ("parts", Value::Array(vec![Value::Bool(true), Value::Null])),
+        ]);
+        const EXPECTED: &str =
+            r#"{"answer":"54331","day":1,"note":"tab\there \"quoted\"","parts":[true,null]}"#;
+        let output = input.to_string();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_parse() {
+        const INPUT: &str = r#"
+        {
+            "days": {"5": {"median": 1.5e3, "samples": [1, -2]}},
+            "name": "café \"bench\""
+        }
+        "#;
+        let output = Value::parse(INPUT).unwrap();
+        let median = output
+            .get("days")
+            .and_then(|days| days.get("5"))
+            .and_then(|day| day.get("median"));
+        assert_eq!(median.and_then(Value::as_f64), Some(1500.0));
+        assert_eq!(
+            output.get("name").and_then(Value::as_str),
+            Some("café \"bench\"")
+        );
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let input = Value::from([
+            (
+                "a",
+                Value::Array(vec![Value::from(0.25), Value::from("\n")]),
+            ),
+            ("b", Value::Object(BTreeMap::new())),
+        ]);
+        let output = Value::parse(&input.to_string()).unwrap();
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        const INPUT: &str = "{\"a\": 1,\n \"b\" 2}";
+        const EXPECTED: &str = "line 2, column 6: expected \":\" after the key, found \"2}\"";
+        let output = Value::parse(INPUT).unwrap_err().to_string();
+        assert_eq!(output, EXPECTED);
+    }
+}
@@ -0,0 +1,52 @@
+//! Solutions for advent of code 2023, exposed as a library so that each day can be
+//! driven through the common [`Solution`] trait.
+//!
+//! The solvers only need `core` and `alloc`, so with the default `std` feature turned off the
+//! library is `no_std` and keeps just the days and what they are built on.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod answers;
+#[cfg(feature = "std")]
+pub mod bench;
+#[cfg(feature = "std")]
+pub mod client;
+#[cfg(feature = "std")]
+pub mod config;
+pub mod days;
+#[cfg(feature = "std")]
+pub mod differential;
+mod error;
+#[cfg(feature = "std")]
+pub mod generate;
+#[cfg(feature = "std")]
+pub mod http;
+#[cfg(feature = "std")]
+pub mod input;
+#[cfg(feature = "std")]
+pub mod json;
+pub mod normalise;
+pub mod num;
+pub mod parallel;
+#[cfg(feature = "std")]
+pub mod progress;
+#[cfg(feature = "std")]
+pub mod registry;
+pub mod render;
+#[cfg(feature = "std")]
+pub mod scaffold;
+#[cfg(feature = "std")]
+pub mod shrink;
+mod solution;
+pub mod stream;
+#[cfg(feature = "std")]
+pub mod submissions;
+pub mod trace;
+#[cfg(feature = "std")]
+pub mod watch;
+
+pub use error::ParseError;
+pub use solution::{Answer, NoAnswer, Part, Solution, SolveError};
@@ -0,0 +1,176 @@
+//! Cleaning up an input before it reaches a day's parser, which can then assume plain LF text
+//! without a byte order mark, tabs or indentation. Inputs saved on Windows have CRLF line endings
+//! and sometimes a BOM, and the examples in the tests are indented to line up with the code.
+//!
+//! Each step that changes the input emits a `normalise` trace event saying what it did.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{trace, ParseError};
+
+const BOM: char = '\u{feff}';
+
+/// What to do with a tab in the input.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Tabs {
+    /// Fails with an error at the first tab, as no puzzle input has one.
+    #[default]
+    Reject,
+    /// Replaces each tab with spaces up to the next multiple of the width.
+    Expand(usize),
+}
+
+/// Strips a BOM, converts CRLF line endings to LF, handles tabs, and removes the indentation
+/// shared by every line that isn't blank. Nothing is copied if the input is already clean.
+pub fn normalise(input: &str, tabs: Tabs) -> Result<Cow<'_, str>, ParseError> {
+    let input = strip_bom(input);
+    let text = convert_line_endings(input);
+    let text = match handle_tabs(&text, tabs)? {
+        Cow::Borrowed(_) => text,
+        Cow::Owned(expanded) => Cow::Owned(expanded),
+    };
+    Ok(match dedent(&text) {
+        Cow::Borrowed(_) => text,
+        Cow::Owned(dedented) => Cow::Owned(dedented),
+    })
+}
+
+/// Removes a byte order mark from the start of the text.
+pub fn strip_bom(text: &str) -> &str {
+    match text.strip_prefix(BOM) {
+        Some(rest) => {
+            trace::event("normalise", &[("stripped", &"byte order mark")]);
+            rest
+        }
+        None => text,
+    }
+}
+
+fn convert_line_endings(text: &str) -> Cow<'_, str> {
+    let count = text.matches("\r\n").count();
+    if count == 0 {
+        return Cow::Borrowed(text);
+    }
+    trace::event("normalise", &[("converted", &"crlf"), ("lines", &count)]);
+    Cow::Owned(text.replace("\r\n", "\n"))
+}
+
+/// Rejects or expands the tabs in the text, depending on `tabs`.
+pub fn handle_tabs(text: &str, tabs: Tabs) -> Result<Cow<'_, str>, ParseError> {
+    let Some(first) = text.find('\t') else {
+        return Ok(Cow::Borrowed(text));
+    };
+    let width = match tabs {
+        Tabs::Reject => return Err(ParseError::new(text, &text[first..], "spaces, not a tab")),
+        Tabs::Expand(width) => width.max(1),
+    };
+    let mut expanded = String::with_capacity(text.len());
+    let mut count = 0;
+    for line in text.split_inclusive('\n') {
+        let mut column = 0;
+        for c in line.chars() {
+            if c == '\t' {
+                let spaces = width - column % width;
+                expanded.extend(core::iter::repeat_n(' ', spaces));
+                column += spaces;
+                count += 1;
+            } else {
+                expanded.push(c);
+                column += 1;
+            }
+        }
+    }
+    trace::event(
+        "normalise",
+        &[("expanded", &"tabs"), ("tabs", &count), ("width", &width)],
+    );
+    Ok(Cow::Owned(expanded))
+}
+
+/// Removes the leading spaces that every line that isn't blank has in common, and the spaces on
+/// blank lines, so that an indented example reads the same as the input it was copied from.
+fn dedent(text: &str) -> Cow<'_, str> {
+    let indent = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start_matches(' ').len())
+        .min()
+        .unwrap_or(0);
+    if indent == 0 {
+        return Cow::Borrowed(text);
+    }
+    let lines: Vec<_> = text
+        .split('\n')
+        .map(|line| match line.trim().is_empty() {
+            true => "",
+            false => &line[indent..],
+        })
+        .collect();
+    trace::event(
+        "normalise",
+        &[("dedented", &indent), ("lines", &lines.len())],
+    );
+    Cow::Owned(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalise() {
+        const INPUT: &str = "\u{feff}seeds: 79 14\r\n\r\nseed-to-soil map:\r\n50 98 2\r\n";
+        const EXPECTED: &str = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n";
+        let (output, events) = trace::collect(|| normalise(INPUT, Tabs::Reject).unwrap());
+        assert_eq!(output, EXPECTED);
+        let events: Vec<_> = events.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            events,
+            [
+                "normalise stripped=\"byte order mark\"",
+                "normalise converted=crlf lines=4",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalise_clean_input_is_borrowed() {
+        const INPUT: &str = "467..114..\n...*......\n";
+        let (output, events) = trace::collect(|| normalise(INPUT, Tabs::Reject).unwrap());
+        assert!(matches!(output, Cow::Borrowed(INPUT)));
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_normalise_dedents() {
+        const INPUT: &str = "
+        467..114..
+          ...*......
+
+        ..35..633.
+        ";
+        const EXPECTED: &str = "\n467..114..\n  ...*......\n\n..35..633.\n";
+        let (output, events) = trace::collect(|| normalise(INPUT, Tabs::Reject).unwrap());
+        assert_eq!(output, EXPECTED);
+        assert_eq!(events[0].to_string(), "normalise dedented=8 lines=6");
+    }
+
+    #[test]
+    fn test_normalise_tabs() {
+        const INPUT: &str = "Time:\t7  15\nDistance:\t9\t40\n";
+        const EXPECTED: &str = "Time:   7  15\nDistance:       9       40\n";
+        let output = normalise(INPUT, Tabs::Reject).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            "line 1, column 6: expected spaces, not a tab, found \"\\t7  15\""
+        );
+        let (output, events) = trace::collect(|| normalise(INPUT, Tabs::Expand(8)).unwrap());
+        assert_eq!(output, EXPECTED);
+        assert_eq!(
+            events[0].to_string(),
+            "normalise expanded=tabs tabs=3 width=8"
+        );
+    }
+}
@@ -0,0 +1,344 @@
+//! The integers that answers are computed in. Days pick a narrow type that their real inputs fit
+//! in, and are solved generically over [`Integer`] so that an answer that overflows it can be
+//! solved again in the type's wide counterpart with [`widen`], instead of wrapping around.
+//!
+//! With [`set_checked`], overflowing the narrow type is an error giving the operation that
+//! overflowed instead, for finding out which inputs a type is too narrow for.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Rem, Sub};
+use core::str::FromStr;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::{trace, Answer, Part};
+
+/// Whether an overflow of a narrow type is an error rather than falling back to the wide type.
+static CHECKED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_checked(checked: bool) {
+    CHECKED.store(checked, Ordering::Relaxed);
+}
+
+pub fn checked() -> bool {
+    CHECKED.load(Ordering::Relaxed)
+}
+
+/// An integer that days can be solved in, with arithmetic that reports overflows.
+pub trait Integer:
+    Copy
+    + Ord
+    + Default
+    + fmt::Debug
+    + fmt::Display
+    + FromStr
+    + From<u8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Send
+    + Sync
+    + 'static
+{
+    /// The name of the type, such as `u16`.
+    const NAME: &'static str;
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The widest type with the same sign, which answers fall back to when this one overflows.
+    type Wide: Integer<Wide = Self::Wide> + From<Self>;
+
+    fn try_add(self, rhs: Self) -> Result<Self, Overflow>;
+
+    fn try_sub(self, rhs: Self) -> Result<Self, Overflow>;
+
+    fn try_mul(self, rhs: Self) -> Result<Self, Overflow>;
+
+    /// The square root rounded down, which panics if the integer is negative.
+    fn isqrt(self) -> Self;
+
+    /// Converts a number that was parsed in the wide type, if it fits in this one.
+    fn narrow(wide: Self::Wide) -> Result<Self, Overflow>;
+}
+
+macro_rules! integer {
+    ($($type:ty => $wide:ty),*) => {
+        $(
+            impl Integer for $type {
+                const NAME: &'static str = stringify!($type);
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                type Wide = $wide;
+
+                fn try_add(self, rhs: Self) -> Result<Self, Overflow> {
+                    self.checked_add(rhs).ok_or_else(|| Overflow::new(self, "+", rhs))
+                }
+
+                fn try_sub(self, rhs: Self) -> Result<Self, Overflow> {
+                    self.checked_sub(rhs).ok_or_else(|| Overflow::new(self, "-", rhs))
+                }
+
+                fn try_mul(self, rhs: Self) -> Result<Self, Overflow> {
+                    self.checked_mul(rhs).ok_or_else(|| Overflow::new(self, "*", rhs))
+                }
+
+                fn isqrt(self) -> Self {
+                    <$type>::isqrt(self)
+                }
+
+                fn narrow(wide: $wide) -> Result<Self, Overflow> {
+                    Self::try_from(wide).map_err(|_| Overflow {
+                        day: None,
+                        part: None,
+                        expression: wide.to_string(),
+                        type_name: Self::NAME,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+integer!(u16 => u128, u32 => u128, u64 => u128, u128 => u128, i32 => i128, i64 => i128, i128 => i128);
+
+impl<N: Integer> Answer for N {
+    fn type_name(&self) -> &'static str {
+        N::NAME
+    }
+}
+
+/// An operation whose result doesn't fit in the type it was done in, or a number that doesn't fit
+/// in the type it was converted to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Overflow {
+    day: Option<u8>,
+    part: Option<Part>,
+    expression: String,
+    type_name: &'static str,
+}
+
+impl Overflow {
+    fn new<N: Integer>(lhs: N, operator: &str, rhs: N) -> Self {
+        Self {
+            day: None,
+            part: None,
+            expression: format!("{lhs} {operator} {rhs}"),
+            type_name: N::NAME,
+        }
+    }
+
+    /// Records the day the overflow happened on.
+    pub fn on_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    /// Records the part that overflowed.
+    pub fn in_part(self, part: Part) -> Self {
+        Self {
+            part: Some(part),
+            ..self
+        }
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn part(&self) -> Option<Part> {
+        self.part
+    }
+
+    /// The operation that overflowed, such as `65535 + 1`, or the number that didn't fit.
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => write!(f, "day {day}, part {part}: ")?,
+            (Some(day), None) => write!(f, "day {day}: ")?,
+            (None, Some(part)) => write!(f, "part {part}: ")?,
+            (None, None) => {}
+        }
+        write!(f, "{} overflows {}", self.expression, self.type_name)
+    }
+}
+
+impl core::error::Error for Overflow {}
+
+/// Adds up numbers, stopping at the first addition that overflows.
+pub fn sum<N: Integer>(numbers: impl IntoIterator<Item = N>) -> Result<N, Overflow> {
+    numbers
+        .into_iter()
+        .try_fold(N::ZERO, |total, number| total.try_add(number))
+}
+
+/// Converts numbers that were parsed in the wide type, if they all fit in `N`.
+pub fn narrow_all<N: Integer>(numbers: &[N::Wide]) -> Result<Vec<N>, Overflow> {
+    numbers.iter().map(|number| N::narrow(*number)).collect()
+}
+
+/// An answer, in the narrow type it was meant to be solved in or the wide type it was solved in
+/// after overflowing that.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Widened<N: Integer> {
+    Narrow(N),
+    Wide(N::Wide),
+}
+
+impl<N: Integer> Widened<N> {
+    pub fn wide(self) -> N::Wide {
+        match self {
+            Widened::Narrow(narrow) => narrow.into(),
+            Widened::Wide(wide) => wide,
+        }
+    }
+
+    /// Adds two answers, in the wide type if either of them is wide or their total overflows,
+    /// which fails like [`widen`] does.
+    pub fn plus(self, other: Self) -> Result<Self, Overflow> {
+        match (self, other) {
+            (Widened::Narrow(a), Widened::Narrow(b)) => {
+                widen(|| a.try_add(b), || N::Wide::from(a).try_add(b.into()))
+            }
+            _ => Ok(Widened::Wide(self.wide().try_add(other.wide())?)),
+        }
+    }
+}
+
+impl<N: Integer> Answer for Widened<N> {
+    /// The narrow type, or the wide one if the answer had to be solved again in it.
+    fn type_name(&self) -> &'static str {
+        match self {
+            Widened::Narrow(_) => N::NAME,
+            Widened::Wide(_) => N::Wide::NAME,
+        }
+    }
+}
+
+impl<N: Integer> Default for Widened<N> {
+    fn default() -> Self {
+        Widened::Narrow(N::ZERO)
+    }
+}
+
+impl<N: Integer> Sum<Widened<N>> for Result<Widened<N>, Overflow> {
+    fn sum<I: Iterator<Item = Widened<N>>>(mut iter: I) -> Self {
+        iter.try_fold(Widened::default(), Widened::plus)
+    }
+}
+
+impl<N: Integer> fmt::Display for Widened<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Widened::Narrow(narrow) => write!(f, "{narrow}"),
+            Widened::Wide(wide) => write!(f, "{wide}"),
+        }
+    }
+}
+
+/// Solves in `N`, and if that overflows, solves again in its wide type, or fails with the
+/// overflow if overflows are being checked. An overflow of the wide type always fails, as there
+/// is nothing wider to fall back to.
+pub fn widen<N: Integer>(
+    narrow: impl FnOnce() -> Result<N, Overflow>,
+    wide: impl FnOnce() -> Result<N::Wide, Overflow>,
+) -> Result<Widened<N>, Overflow> {
+    widen_with(checked(), narrow, wide)
+}
+
+fn widen_with<N: Integer>(
+    checked: bool,
+    narrow: impl FnOnce() -> Result<N, Overflow>,
+    wide: impl FnOnce() -> Result<N::Wide, Overflow>,
+) -> Result<Widened<N>, Overflow> {
+    let overflow = match narrow() {
+        Ok(answer) => return Ok(Widened::Narrow(answer)),
+        Err(overflow) => overflow,
+    };
+    if checked {
+        return Err(overflow);
+    }
+    trace::event("widen", &[("overflow", &overflow), ("to", &N::Wide::NAME)]);
+    wide().map(Widened::Wide)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overflow() {
+        const EXPECTED: &[&str] = &[
+            "65535 + 1 overflows u16",
+            "0 - 1 overflows u32",
+            "-2147483648 * 2 overflows i32",
+            "4294967296 overflows u32",
+        ];
+        let output = [
+            u16::MAX.try_add(1).unwrap_err(),
+            0u32.try_sub(1).unwrap_err(),
+            i32::MIN.try_mul(2).unwrap_err(),
+            u32::narrow(1 << 32).unwrap_err(),
+        ];
+        let output: Vec<_> = output.iter().map(ToString::to_string).collect();
+        assert_eq!(output, EXPECTED);
+        assert_eq!(i32::narrow(-5), Ok(-5));
+    }
+
+    #[test]
+    fn test_sum() {
+        assert_eq!(sum([1u16, 2, 3]), Ok(6));
+        assert_eq!(
+            sum([60000u16, 5000, 1000]).unwrap_err().expression(),
+            "65000 + 1000"
+        );
+    }
+
+    #[test]
+    fn test_widen() {
+        let solve = |checked| widen_with::<u16>(checked, || 60000u16.try_add(6000), || Ok(66000));
+        let (output, events) = trace::collect(|| solve(false).unwrap());
+        assert_eq!(output, Widened::Wide(66000));
+        assert_eq!(output.to_string(), "66000");
+        assert_eq!(output.type_name(), "u128");
+        assert_eq!(Widened::<u16>::Narrow(5).type_name(), "u16");
+        assert_eq!(
+            events[0].to_string(),
+            "widen overflow=\"60000 + 6000 overflows u16\" to=u128"
+        );
+        let output = solve(true).unwrap_err().on_day(1).in_part(Part::Two);
+        assert_eq!(
+            output.to_string(),
+            "day 1, part 2: 60000 + 6000 overflows u16"
+        );
+        let output = widen_with::<u64>(false, || u64::MAX.try_add(1), || u128::MAX.try_add(1));
+        assert_eq!(
+            output.unwrap_err().to_string(),
+            "340282366920938463463374607431768211455 + 1 overflows u128"
+        );
+    }
+
+    #[test]
+    fn test_widened_sum() {
+        let output: Result<Widened<u16>, _> =
+            [40000, 30000, 1].into_iter().map(Widened::Narrow).sum();
+        assert_eq!(output, Ok(Widened::Wide(70001)));
+        let output: Result<Widened<i32>, _> = [-4, 9].into_iter().map(Widened::Narrow).sum();
+        assert_eq!(output, Ok(Widened::Narrow(5)));
+    }
+}
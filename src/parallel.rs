@@ -0,0 +1,232 @@
+//! Opt-in parallelism within a day. Work that is independent for each line or item is split
+//! between scoped threads, and the results are put back in their original order so that every
+//! answer is identical to solving on a single thread. Without `std` there are no threads, and
+//! everything runs on the calling one.
+
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "std")]
+use std::any::Any;
+#[cfg(feature = "std")]
+use std::cell::Cell;
+#[cfg(feature = "std")]
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(feature = "std")]
+use std::string::String;
+#[cfg(feature = "std")]
+use std::thread;
+
+use crate::trace;
+
+/// How many threads [`map`] splits work between. One means everything runs on the calling
+/// thread, which is the default.
+static THREADS: AtomicUsize = AtomicUsize::new(1);
+
+/// Sets how many threads days split their work between, where zero is taken as one.
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads.max(1), Ordering::Relaxed);
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    /// The number of threads given to [`with_threads`] on this thread, which takes the place of
+    /// the one set with [`set_threads`].
+    static SCOPED: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+#[cfg(feature = "std")]
+pub fn threads() -> usize {
+    SCOPED
+        .with(Cell::get)
+        .unwrap_or_else(|| THREADS.load(Ordering::Relaxed))
+}
+
+#[cfg(not(feature = "std"))]
+pub fn threads() -> usize {
+    THREADS.load(Ordering::Relaxed)
+}
+
+/// Runs `f` with the work started on this thread split between `threads` threads, whatever
+/// [`set_threads`] was given, so that something solved with a different number of threads
+/// doesn't change it for everything else running at the same time.
+#[cfg(feature = "std")]
+pub fn with_threads<R>(threads: usize, f: impl FnOnce() -> R) -> R {
+    /// Puts back the number of threads from before, even if `f` panics.
+    struct Restore(Option<usize>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED.with(|scoped| scoped.set(self.0));
+        }
+    }
+
+    let _restore = Restore(SCOPED.with(|scoped| scoped.replace(Some(threads.max(1)))));
+    f()
+}
+
+/// Applies `f` to every item, split between the threads set with [`set_threads`], and returns
+/// the results in the order of the items. While trace events are being collected everything runs
+/// on the calling thread, which is the one they're collected on.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let threads = if trace::enabled() { 1 } else { threads() };
+    map_with(threads, items, f)
+}
+
+#[cfg(not(feature = "std"))]
+fn map_with<T, R>(_: usize, items: &[T], f: impl Fn(&T) -> R) -> Vec<R> {
+    items.iter().map(f).collect()
+}
+
+/// Applies `f` to every item, split into contiguous chunks between `threads` scoped threads. A
+/// panic on any of them is passed on as it was raised.
+#[cfg(feature = "std")]
+pub fn map_with<T: Sync, R: Send>(
+    threads: usize,
+    items: &[T],
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    if threads <= 1 || items.len() < 2 {
+        return items.iter().map(f).collect();
+    }
+    let chunk = items.len().div_ceil(threads);
+    let f = &f;
+    thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<Vec<_>>()))
+            .collect();
+        let mut results = Vec::with_capacity(items.len());
+        for handle in handles {
+            match handle.join() {
+                Ok(chunk) => results.extend(chunk),
+                Err(payload) => panic::resume_unwind(payload),
+            }
+        }
+        results
+    })
+}
+
+/// Applies `f` to every item on a pool of `workers` scoped threads, each of which takes the next
+/// item as soon as it finishes its last, so one slow item doesn't hold up the rest. A panic is
+/// caught and its message returned in place of that item's result, and the results are in the
+/// order of the items.
+#[cfg(feature = "std")]
+pub fn pool<T: Sync, R: Send>(
+    workers: usize,
+    items: &[T],
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<Result<R, String>> {
+    let next = AtomicUsize::new(0);
+    let (f, next) = (&f, &next);
+    let mut results: Vec<_> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers.clamp(1, items.len().max(1)))
+            .map(|_| {
+                scope.spawn(move || {
+                    let mut results = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break;
+                        };
+                        let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                            .map_err(|payload| panic_message(&*payload));
+                        results.push((index, result));
+                    }
+                    results
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("panics are caught"))
+            .collect()
+    });
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// The message a panic was raised with, or an empty string if it wasn't raised with one.
+#[cfg(feature = "std")]
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => payload
+            .downcast_ref::<String>()
+            .cloned()
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use crate::{generate, Part};
+
+    #[test]
+    fn test_map_with_keeps_order() {
+        let input: Vec<u32> = (0..1001).collect();
+        let expected: Vec<u32> = input.iter().map(|n| n * n).collect();
+        for threads in [0, 1, 2, 3, 8, 2000] {
+            let output = map_with(threads, &input, |n| n * n);
+            assert_eq!(output, expected, "{threads} threads");
+        }
+    }
+
+    #[test]
+    fn test_map_with_passes_on_panics() {
+        let output = panic::catch_unwind(|| {
+            map_with(4, &[1, 2, 3, 4, 5, 6], |n| {
+                assert_ne!(*n, 5, "five");
+                *n
+            })
+        });
+        let payload = output.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.contains("five"), "{message}");
+    }
+
+    #[test]
+    fn test_pool_keeps_order_and_catches_panics() {
+        let input: Vec<u32> = (0..100).collect();
+        for workers in [0, 1, 3, 200] {
+            let output = pool(workers, &input, |n| {
+                if n % 7 == 3 {
+                    panic!("{n}");
+                }
+                n * 2
+            });
+            let expected: Vec<_> = input
+                .iter()
+                .map(|n| match n % 7 {
+                    3 => Err(n.to_string()),
+                    _ => Ok(n * 2),
+                })
+                .collect();
+            assert_eq!(output, expected, "{workers} workers");
+        }
+    }
+
+    #[test]
+    fn test_threads_give_identical_answers() {
+        let solve_all = || -> Vec<Vec<String>> {
+            [1, 2, 4, 8, 9]
+                .into_iter()
+                .map(|day| {
+                    let registration = registry::find(day).unwrap();
+                    let input = generate::generate(registration.day(), 1, 200).unwrap();
+                    let solved = registration.solve(&input, &Part::ALL).unwrap();
+                    solved
+                        .answers
+                        .into_iter()
+                        .map(|answer| answer.value)
+                        .collect()
+                })
+                .collect()
+        };
+        let expected = with_threads(1, solve_all);
+        let output = with_threads(4, solve_all);
+        assert_eq!(output, expected);
+        assert_eq!(threads(), THREADS.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::answers::Answers;
+use crate::bench::{self, Phase};
+use crate::input::{self, InputSource};
+use crate::registry::{self, Registration};
+use crate::Part;
+
+/// The heading that the progress table follows in the README.
+const HEADING: &str = "# Progress";
+
+/// How far along a part of a day is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// The part gives its known answer for the real input.
+    Done,
+    /// The day has a solution, but this part doesn't give a known answer yet.
+    InProgress,
+    NotStarted,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Done => write!(f, ":heavy_check_mark:"),
+            Status::InProgress => write!(f, ":construction:"),
+            Status::NotStarted => write!(f, ":x:"),
+        }
+    }
+}
+
+/// A row of the progress table.
+#[derive(Debug, PartialEq)]
+pub struct Row {
+    pub day: u8,
+    pub title: Option<&'static str>,
+    pub parts: [Status; 2],
+    /// The median time to parse the input and solve both parts, if it was measured.
+    pub runtime: Option<Duration>,
+}
+
+impl Row {
+    fn not_started(day: u8) -> Self {
+        Self {
+            day,
+            title: None,
+            parts: [Status::NotStarted; 2],
+            runtime: None,
+        }
+    }
+}
+
+impl fmt::Display for Row {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.title {
+            Some("") => write!(f, "|[Day {}]({})", self.day, link(self.day))?,
+            Some(title) => write!(f, "|[Day {}: {title}]({})", self.day, link(self.day))?,
+            None => write!(f, "|Day {}", self.day)?,
+        }
+        write!(f, "|{}|{}|", self.parts[0], self.parts[1])
+    }
+}
+
+/// Works out the progress of every day, checking registered days against their known answers
+/// using the inputs in `input_dir`, and measuring their runtimes if `timed` is set.
+pub fn rows(answers: &Answers, input_dir: &Path, timed: bool) -> Vec<Row> {
+    (1..=25)
+        .map(|day| match registry::find(day) {
+            Some(registration) => check(registration, answers, input_dir, timed),
+            None => Row::not_started(day),
+        })
+        .collect()
+}
+
+fn check(registration: &Registration, answers: &Answers, input_dir: &Path, timed: bool) -> Row {
+    let day = registration.day();
+    let mut row = Row {
+        day,
+        title: Some(registration.title()),
+        parts: [Status::InProgress; 2],
+        runtime: None,
+    };
+    let source = InputSource::Path(input::path_in(input_dir, day));
+    let Ok(input) = input::load(day, &source) else {
+        return row;
+    };
+    let Ok(solved) = registration.solve(&input, &Part::ALL) else {
+        return row;
+    };
+    for answer in solved.answers {
+        if answers.get(day, answer.part) == Some(answer.value.as_str()) {
+            row.parts[answer.part.number() as usize - 1] = Status::Done;
+        }
+    }
+    if timed {
+        row.runtime = bench::measure(registration, &input)
+            .ok()
+            .map(|measurement| {
+                Phase::ALL
+                    .iter()
+                    .filter_map(|phase| measurement.phases.get(phase))
+                    .map(|stats| stats.median)
+                    .sum()
+            });
+    }
+    row
+}
+
+/// Renders the progress table, with a runtime column if any row has a runtime.
+pub fn render_table(rows: &[Row]) -> String {
+    let timed = rows.iter().any(|row| row.runtime.is_some());
+    let mut table = if timed {
+        "|Puzzle|Part 1|Part 2|Runtime|\n|:-:|:-:|:-:|:-:|\n".to_string()
+    } else {
+        "|Puzzle|Part 1|Part 2|\n|:-:|:-:|:-:|\n".to_string()
+    };
+    for row in rows {
+        table.push_str(&row.to_string());
+        if timed {
+            if let Some(runtime) = row.runtime {
+                table.push_str(&format!("{runtime:.1?}"));
+            }
+            table.push('|');
+        }
+        table.push('\n');
+    }
+    table
+}
+
+/// Replaces the table following the progress heading in `readme` with `table`.
+pub fn replace_table(readme: &str, table: &str) -> Result<String, String> {
+    let start = readme
+        .lines()
+        .position(|line| line.trim() == HEADING)
+        .ok_or_else(|| format!("Could not find the {HEADING} heading"))?
+        + 1;
+    let lines: Vec<_> = readme.lines().collect();
+    let end = start
+        + lines[start..]
+            .iter()
+            .take_while(|line| line.starts_with('|'))
+            .count();
+    let mut text: String = lines[..start]
+        .iter()
+        .map(|line| format!("{line}\n"))
+        .collect();
+    text.push_str(table);
+    text.extend(lines[end..].iter().map(|line| format!("{line}\n")));
+    Ok(text)
+}
+
+fn link(day: u8) -> String {
+    format!("https://adventofcode.com/2023/day/{day}")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::*;
+
+    #[test]
+    fn test_rows() {
+        let answers = Answers::parse("[day09]\npart1 = 114\npart2 = 3\n").unwrap();
+        let dir = env::temp_dir().join(format!("aoc-2023-progress-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            input::path_in(&dir, 9),
+            "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n",
+        )
+        .unwrap();
+        let output = rows(&answers, &dir, false);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(output.len(), 25);
+        assert_eq!(
+            output[8],
+            Row {
+                day: 9,
+                title: Some("Mirage Maintenance"),
+                parts: [Status::Done, Status::InProgress],
+                runtime: None,
+            }
+        );
+        assert_eq!(output[0].parts, [Status::InProgress; 2]);
+        for day in (1..=25).filter(|day| registry::find(*day).is_none()) {
+            assert_eq!(output[day as usize - 1], Row::not_started(day));
+        }
+    }
+
+    #[test]
+    fn test_render_table() {
+        let input = [
+            Row {
+                day: 1,
+                title: Some("Trebuchet?!"),
+                parts: [Status::Done, Status::InProgress],
+                runtime: None,
+            },
+            Row::not_started(2),
+        ];
+        const EXPECTED: &str = "\
+|Puzzle|Part 1|Part 2|
+|:-:|:-:|:-:|
+|[Day 1: Trebuchet?!](https://adventofcode.com/2023/day/1)|:heavy_check_mark:|:construction:|
+|Day 2|:x:|:x:|
+";
+        let output = render_table(&input);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_render_table_timed() {
+        let input = [
+            Row {
+                day: 1,
+                title: Some(""),
+                parts: [Status::Done; 2],
+                runtime: Some(Duration::from_micros(1240)),
+            },
+            Row::not_started(2),
+        ];
+        const EXPECTED: &str = "\
+|Puzzle|Part 1|Part 2|Runtime|
+|:-:|:-:|:-:|:-:|
+|[Day 1](https://adventofcode.com/2023/day/1)|:heavy_check_mark:|:heavy_check_mark:|1.2ms|
+|Day 2|:x:|:x:||
+";
+        let output = render_table(&input);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_replace_table() {
+        const INPUT: &str = "# aoc-2023\n\n# Progress\n|Puzzle|\n|:-:|\n|Day 1|\n\nMore text\n";
+        const EXPECTED: &str = "# aoc-2023\n\n# Progress\n|new|\n\nMore text\n";
+        let output = replace_table(INPUT, "|new|\n");
+        assert_eq!(output.as_deref(), Ok(EXPECTED));
+    }
+}
@@ -0,0 +1,254 @@
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+use crate::days::{
+    day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+    day07::Day07, day08::Day08, day09::Day09,
+};
+use crate::normalise::{self, Tabs};
+use crate::stream::{self, StreamError, Streaming};
+use crate::trace;
+pub use crate::SolveError;
+use crate::{Answer as _, Part, Solution};
+
+/// Every solution known to the library, in day order.
+pub const SOLUTIONS: &[Registration] = &[
+    Registration::streaming::<Day01>(),
+    Registration::streaming::<Day02>(),
+    Registration::new::<Day03>(),
+    Registration::streaming::<Day04>(),
+    Registration::new::<Day05>(),
+    Registration::new::<Day06>(),
+    Registration::new::<Day07>(),
+    Registration::new::<Day08>(),
+    Registration::streaming::<Day09>(),
+];
+
+type Solver = fn(&str, &[Part]) -> Result<Solved, SolveError>;
+type Streamer = fn(&mut dyn BufRead) -> Result<Vec<Answer>, StreamError>;
+
+/// The outcome of solving a day, along with how long each phase took.
+#[derive(Debug)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    /// The name of the type the answer was produced as, such as `u32`.
+    pub type_name: &'static str,
+    pub time: Duration,
+}
+
+/// A type-erased handle to a [`Solution`] so that days can be looked up and run at runtime.
+#[derive(Clone, Copy)]
+pub struct Registration {
+    day: u8,
+    title: &'static str,
+    solve: Solver,
+    stream: Option<Streamer>,
+}
+
+impl Registration {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            title: S::TITLE,
+            solve: solve::<S>,
+            stream: None,
+        }
+    }
+
+    /// Registers a solution that can also be solved from a reader with [`Registration::stream`].
+    pub const fn streaming<S: Streaming>() -> Self {
+        Self {
+            stream: Some(stream::<S>),
+            ..Self::new::<S>()
+        }
+    }
+
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub const fn title(&self) -> &'static str {
+        self.title
+    }
+
+    /// Parses the input once and solves the requested parts, returning the formatted answers.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Solved, SolveError> {
+        (self.solve)(input, parts)
+    }
+
+    /// Solves both parts from `reader` a line at a time, or returns `None` if the day can only
+    /// be solved with the whole input in memory. Parsing and solving happen together, so each
+    /// answer's time is the time of the whole pass.
+    pub fn stream(&self, reader: &mut dyn BufRead) -> Option<Result<Vec<Answer>, StreamError>> {
+        self.stream.map(|stream| stream(reader))
+    }
+}
+
+/// Finds the registered solution for the given day.
+pub fn find(day: u8) -> Option<&'static Registration> {
+    SOLUTIONS
+        .iter()
+        .find(|registration| registration.day == day)
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Solved, SolveError> {
+    let start = Instant::now();
+    let normalised;
+    let parsed = {
+        let _scope = trace::scope("parse", &[]);
+        normalised =
+            normalise::normalise(input, Tabs::Reject).map_err(|error| error.on_day(S::DAY))?;
+        S::parse(&normalised)?
+    };
+    let parse_time = start.elapsed();
+    let answers = parts
+        .iter()
+        .map(|part| {
+            let _scope = trace::scope("part", &[("part", part)]);
+            let start = Instant::now();
+            let (value, type_name) =
+                match part {
+                    Part::One => S::solve_part_1(&parsed)
+                        .map(|answer| (answer.to_string(), answer.type_name())),
+                    Part::Two => S::solve_part_2(&parsed)
+                        .map(|answer| (answer.to_string(), answer.type_name())),
+                }
+                .map_err(|error| error.on_day(S::DAY).in_part(*part))?;
+            Ok(Answer {
+                part: *part,
+                value,
+                type_name,
+                time: start.elapsed(),
+            })
+        })
+        .collect::<Result<_, SolveError>>()?;
+    Ok(Solved {
+        parse_time,
+        answers,
+    })
+}
+
+fn stream<S: Streaming>(reader: &mut dyn BufRead) -> Result<Vec<Answer>, StreamError> {
+    let start = Instant::now();
+    let (part_1, part_2) = {
+        let _scope = trace::scope("stream", &[]);
+        stream::solve::<S>(reader)?
+    };
+    let time = start.elapsed();
+    Ok(vec![
+        Answer {
+            part: Part::One,
+            value: part_1.to_string(),
+            type_name: part_1.type_name(),
+            time,
+        },
+        Answer {
+            part: Part::Two,
+            value: part_2.to_string(),
+            type_name: part_2.type_name(),
+            time,
+        },
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solutions_in_day_order() {
+        let output: Vec<_> = SOLUTIONS.iter().map(Registration::day).collect();
+        let mut expected = output.clone();
+        expected.sort();
+        expected.dedup();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_find() {
+        const INPUT: u8 = 5;
+        const EXPECTED: Option<u8> = Some(5);
+        let output = find(INPUT).map(Registration::day);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_find_unregistered() {
+        const INPUT: u8 = 25;
+        let output = find(INPUT);
+        assert!(output.is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        const INPUT: &str = "
+        0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45
+        ";
+        const EXPECTED: &[(Part, &str)] = &[(Part::Two, "2"), (Part::One, "114")];
+        let output = find(9)
+            .unwrap()
+            .solve(INPUT, &[Part::Two, Part::One])
+            .unwrap();
+        let output: Vec<_> = output
+            .answers
+            .iter()
+            .map(|answer| (answer.part, answer.value.as_str()))
+            .collect();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_stream() {
+        const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+        const EXPECTED: &[(Part, &str)] = &[(Part::One, "114"), (Part::Two, "2")];
+        let output = find(9)
+            .unwrap()
+            .stream(&mut INPUT.as_bytes())
+            .unwrap()
+            .unwrap();
+        let output: Vec<_> = output
+            .iter()
+            .map(|answer| (answer.part, answer.value.as_str()))
+            .collect();
+        assert_eq!(output, EXPECTED);
+        assert!(find(5).unwrap().stream(&mut INPUT.as_bytes()).is_none());
+    }
+
+    #[test]
+    fn test_solve_normalises_input() {
+        const INPUT: &str = "\u{feff}seeds: 1 2\r\n\r\nseed-to-soil map:\r\n5 1 3\r\n";
+        const EXPECTED: &[(Part, &str)] = &[(Part::One, "5"), (Part::Two, "5")];
+        let output = find(5).unwrap().solve(INPUT, &Part::ALL).unwrap();
+        let output: Vec<_> = output
+            .answers
+            .iter()
+            .map(|answer| (answer.part, answer.value.as_str()))
+            .collect();
+        assert_eq!(output, EXPECTED);
+        let output = find(5)
+            .unwrap()
+            .solve("seeds:\t1 2", &Part::ALL)
+            .unwrap_err();
+        assert_eq!(
+            output.to_string(),
+            "day 5, line 1, column 7: expected spaces, not a tab, found \"\\t1 2\""
+        );
+    }
+
+    #[test]
+    fn test_solve_invalid_input() {
+        const INPUT: &str = "0 3 6 x 12 15";
+        const EXPECTED: &str = "day 9, line 1, column 7: expected a number, found \"x\"";
+        let output = find(9).unwrap().solve(INPUT, &Part::ALL).unwrap_err();
+        assert_eq!(output.to_string(), EXPECTED);
+    }
+}
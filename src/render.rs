@@ -0,0 +1,335 @@
+//! Drawing the grids of grid puzzles with each cell coloured by what it means to the solution,
+//! either for the terminal with ANSI escape codes or as an SVG image for reports.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt::Write;
+
+use crate::days::day03;
+use crate::normalise::{self, Tabs};
+use crate::ParseError;
+
+/// Every day that can be rendered, in day order.
+pub const RENDERERS: &[(u8, Renderer)] = &[(3, day03::render)];
+
+/// Draws a day's input as a grid, or fails to parse it.
+pub type Renderer = fn(&str) -> Result<Grid, ParseError>;
+
+/// Finds the renderer for the given day.
+pub fn find(day: u8) -> Option<Renderer> {
+    RENDERERS
+        .iter()
+        .find(|(renderer_day, _)| *renderer_day == day)
+        .map(|(_, renderer)| *renderer)
+}
+
+/// Draws a day's input with its renderer, after normalising it the same way as before solving,
+/// or returns `None` if the day can't be rendered.
+pub fn render(day: u8, input: &str) -> Option<Result<Grid, ParseError>> {
+    let renderer = find(day)?;
+    Some(
+        normalise::normalise(input, Tabs::Reject)
+            .map_err(|error| error.on_day(day))
+            .and_then(|input| renderer(&input)),
+    )
+}
+
+/// The colours a cell can be drawn in, which are the standard terminal colours so they look the
+/// same in either output.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Grey,
+}
+
+impl Colour {
+    const fn ansi(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::Grey => 90,
+        }
+    }
+
+    const fn hex(self) -> &'static str {
+        match self {
+            Colour::Red => "#e06c75",
+            Colour::Green => "#98c379",
+            Colour::Yellow => "#e5c07b",
+            Colour::Blue => "#61afef",
+            Colour::Magenta => "#c678dd",
+            Colour::Cyan => "#56b6c2",
+            Colour::Grey => "#5c6370",
+        }
+    }
+}
+
+/// How a cell is drawn. The default is the plain text colour.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Style {
+    pub colour: Option<Colour>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub const fn colour(colour: Colour) -> Self {
+        Self {
+            colour: Some(colour),
+            bold: false,
+        }
+    }
+
+    pub const fn bold(self) -> Self {
+        Self { bold: true, ..self }
+    }
+}
+
+/// The width of a cell in an SVG, and the height of a row, for 14px monospaced text.
+const CELL_WIDTH: usize = 9;
+const CELL_HEIGHT: usize = 16;
+const SVG_BACKGROUND: &str = "#282c34";
+const SVG_TEXT: &str = "#abb2bf";
+
+/// A grid of characters, each with a style, and a legend saying what each style means.
+#[derive(Debug, PartialEq)]
+pub struct Grid {
+    width: usize,
+    cells: Vec<(char, Style)>,
+    legend: Vec<(Style, &'static str)>,
+}
+
+impl Grid {
+    /// A grid with a row for each of `rows`, as wide as the longest of them. Shorter rows are
+    /// padded with spaces.
+    pub fn from_rows<'a>(rows: impl IntoIterator<Item = &'a str>) -> Self {
+        let rows: Vec<Vec<char>> = rows.into_iter().map(|row| row.chars().collect()).collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let cells = rows
+            .iter()
+            .flat_map(|row| {
+                (0..width).map(|x| (row.get(x).copied().unwrap_or(' '), Style::default()))
+            })
+            .collect();
+        Self {
+            width,
+            cells,
+            legend: vec![],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    /// The character and style of a cell, or `None` outside of the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<(char, Style)> {
+        (x < self.width)
+            .then(|| self.cells.get(y * self.width + x).copied())
+            .flatten()
+    }
+
+    /// Styles a cell, ignoring cells outside of the grid.
+    pub fn paint(&mut self, x: usize, y: usize, style: Style) {
+        if x < self.width {
+            if let Some(cell) = self.cells.get_mut(y * self.width + x) {
+                cell.1 = style;
+            }
+        }
+    }
+
+    /// Adds a line to the legend saying what a style means.
+    pub fn describe(&mut self, style: Style, meaning: &'static str) {
+        self.legend.push((style, meaning));
+    }
+
+    /// The grid with ANSI escape codes for the terminal, followed by the legend.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.cells.chunks(self.width.max(1)) {
+            let mut current = Style::default();
+            for (c, style) in row {
+                if *style != current {
+                    out.push_str(&ansi_code(*style));
+                    current = *style;
+                }
+                out.push(*c);
+            }
+            if current != Style::default() {
+                out.push_str(&ansi_code(Style::default()));
+            }
+            out.push('\n');
+        }
+        if !self.legend.is_empty() {
+            out.push('\n');
+        }
+        for (style, meaning) in &self.legend {
+            let reset = ansi_code(Style::default());
+            let _ = writeln!(out, "{}■{reset} {meaning}", ansi_code(*style));
+        }
+        out
+    }
+
+    /// The grid as an SVG image of monospaced text, followed by the legend.
+    pub fn to_svg(&self) -> String {
+        // the legend is a row below the grid, with a row between them.
+        let legend_gap = usize::from(!self.legend.is_empty());
+        let rows = self.height() + legend_gap + self.legend.len();
+        let (width, height) = ((self.width + 2) * CELL_WIDTH, (rows + 1) * CELL_HEIGHT);
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             font-family=\"monospace\" font-size=\"14\" xml:space=\"preserve\">"
+        );
+        let _ = writeln!(
+            out,
+            "<rect width=\"100%\" height=\"100%\" fill=\"{SVG_BACKGROUND}\"/>"
+        );
+        let lines = self
+            .cells
+            .chunks(self.width.max(1))
+            .map(|row| row.to_vec())
+            .chain(self.legend.iter().map(|(style, meaning)| {
+                let mut line = vec![('■', *style), (' ', Style::default())];
+                line.extend(meaning.chars().map(|c| (c, Style::default())));
+                line
+            }));
+        for (index, line) in lines.enumerate() {
+            let row = if index < self.height() {
+                index
+            } else {
+                index + legend_gap
+            };
+            let _ = write!(
+                out,
+                "<text x=\"{CELL_WIDTH}\" y=\"{}\" fill=\"{SVG_TEXT}\">",
+                (row + 1) * CELL_HEIGHT
+            );
+            for run in line.chunk_by(|a, b| a.1 == b.1) {
+                let text: String = run.iter().map(|(c, _)| *c).collect();
+                let text = escape_xml(&text);
+                match run[0].1 {
+                    Style {
+                        colour: None,
+                        bold: false,
+                    } => out.push_str(&text),
+                    Style { colour, bold } => {
+                        out.push_str("<tspan");
+                        if let Some(colour) = colour {
+                            let _ = write!(out, " fill=\"{}\"", colour.hex());
+                        }
+                        if bold {
+                            out.push_str(" font-weight=\"bold\"");
+                        }
+                        let _ = write!(out, ">{text}</tspan>");
+                    }
+                }
+            }
+            out.push_str("</text>\n");
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+fn ansi_code(style: Style) -> String {
+    match style {
+        Style {
+            colour: None,
+            bold: false,
+        } => "\x1b[0m".to_string(),
+        Style { colour, bold } => {
+            let mut codes = vec!["0".to_string()];
+            if bold {
+                codes.push("1".to_string());
+            }
+            if let Some(colour) = colour {
+                codes.push(colour.ansi().to_string());
+            }
+            format!("\x1b[{}m", codes.join(";"))
+        }
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GREEN: Style = Style::colour(Colour::Green);
+
+    fn grid() -> Grid {
+        let mut grid = Grid::from_rows(["12.", "&*"]);
+        grid.paint(0, 0, GREEN);
+        grid.paint(1, 0, GREEN);
+        grid.paint(1, 1, Style::colour(Colour::Yellow).bold());
+        grid.describe(GREEN, "part number");
+        grid
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let output = grid();
+        assert_eq!((output.width(), output.height()), (3, 2));
+        assert_eq!(output.get(0, 1), Some(('&', Style::default())));
+        assert_eq!(output.get(2, 1), Some((' ', Style::default())));
+        assert_eq!(output.get(3, 0), None);
+        assert_eq!(output.get(0, 2), None);
+    }
+
+    #[test]
+    fn test_to_ansi() {
+        const EXPECTED: &str = "\x1b[0;32m12\x1b[0m.\n&\x1b[0;1;33m*\x1b[0m \n\n\
+            \x1b[0;32m■\x1b[0m part number\n";
+        let output = grid().to_ansi();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let output = grid().to_svg();
+        assert!(output.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"45\""));
+        assert!(output.contains(
+            "<text x=\"9\" y=\"16\" fill=\"#abb2bf\"><tspan fill=\"#98c379\">12</tspan>.</text>"
+        ));
+        assert!(output.contains(
+            "<text x=\"9\" y=\"32\" fill=\"#abb2bf\">&amp;<tspan fill=\"#e5c07b\" font-weight=\"bold\">*</tspan> </text>"
+        ));
+        assert!(output.contains("<text x=\"9\" y=\"64\" fill=\"#abb2bf\"><tspan fill=\"#98c379\">■</tspan> part number</text>"));
+        assert!(output.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_find() {
+        assert!(find(3).is_some());
+        assert!(find(5).is_none());
+    }
+
+    #[test]
+    fn test_render_normalises_input() {
+        const INPUT: &str = "\u{feff}467..114..\r\n...*......\r\n..35..633.\r\n";
+        let output = render(3, INPUT).unwrap().unwrap();
+        assert_eq!((output.width(), output.height()), (10, 3));
+        assert_eq!(output.get(0, 0).unwrap().0, '4');
+        assert!(render(5, INPUT).is_none());
+    }
+}
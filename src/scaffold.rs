@@ -0,0 +1,308 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::input::{self, DEFAULT_INPUT_DIR};
+use crate::progress::Status;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs.template");
+
+/// Creates the module for a new day from the template and wires it into the rest of the crate,
+/// returning the paths of the files that were created or changed.
+///
+/// Every file is checked and updated in memory before any is written, so an error leaves the
+/// tree as it was.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let module_path = root.join(format!("src/days/day{day:02}.rs"));
+    if module_path.exists() {
+        return Err(format!("{} already exists", module_path.display()));
+    }
+    let mut files = vec![(module_path, render_template(day))];
+    for (path, update) in [
+        (
+            "src/days/mod.rs",
+            add_module as fn(&str, u8) -> Result<String, String>,
+        ),
+        ("src/registry.rs", register),
+        ("src/input.rs", embed_input),
+        ("README.md", mark_in_progress),
+    ] {
+        let path = root.join(path);
+        let text = fs::read_to_string(&path)
+            .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+        let text = update(&text, day).map_err(|error| format!("{}: {error}", path.display()))?;
+        files.push((path, text));
+    }
+    let input_path = input::path_in(&root.join(DEFAULT_INPUT_DIR), day);
+    if !input_path.exists() {
+        files.push((input_path, String::new()));
+    }
+    for (path, text) in &files {
+        fs::write(path, text)
+            .map_err(|error| format!("Failed to write {}: {error}", path.display()))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn render_template(day: u8) -> String {
+    TEMPLATE
+        .replace("{{DAY}}", &format!("{day:02}"))
+        .replace("{{NUMBER}}", &day.to_string())
+}
+
+/// Adds `pub mod dayNN;` to the list of day modules.
+fn add_module(text: &str, day: u8) -> Result<String, String> {
+    let mut lines: Vec<_> = text.lines().map(String::from).collect();
+    insert_sorted(
+        &mut lines,
+        0..text.lines().count(),
+        format!("pub mod day{day:02};"),
+    )?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day to the imports and the list of solutions in the registry.
+fn register(text: &str, day: u8) -> Result<String, String> {
+    const IMPORT_START: &str = "use crate::days::{";
+    const SOLUTIONS_START: &str = "pub const SOLUTIONS: &[Registration] = &[";
+    let (before, rest) = text
+        .split_once(IMPORT_START)
+        .ok_or("Could not find the imports of the days")?;
+    let (imports, after) = rest
+        .split_once("};")
+        .ok_or("Could not find the end of the imports of the days")?;
+    let mut imports: Vec<_> = imports
+        .split(',')
+        .map(str::trim)
+        .filter(|import| !import.is_empty())
+        .map(String::from)
+        .collect();
+    imports.push(format!("day{day:02}::Day{day:02}"));
+    imports.sort();
+    let text = format!("{before}{}{after}", format_use_list(IMPORT_START, &imports));
+
+    let mut lines: Vec<_> = text.lines().map(String::from).collect();
+    let start = lines
+        .iter()
+        .position(|line| line == SOLUTIONS_START)
+        .ok_or("Could not find the list of solutions")?
+        + 1;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or("Could not find the end of the list of solutions")?;
+    insert_sorted(
+        &mut lines,
+        start..end,
+        format!("    Registration::new::<Day{day:02}>(),"),
+    )?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day's data file to the inputs embedded by the `embed-inputs` feature.
+fn embed_input(text: &str, day: u8) -> Result<String, String> {
+    let mut lines: Vec<_> = text.lines().map(String::from).collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("fn embedded("))
+        .ok_or("Could not find the embedded inputs")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "_ => None,")
+            .ok_or("Could not find the end of the embedded inputs")?;
+    let first = start
+        + lines[start..end]
+            .iter()
+            .position(|line| line.contains("include_str!"))
+            .ok_or("Could not find the embedded inputs")?;
+    insert_sorted(
+        &mut lines,
+        first..end,
+        format!("        {day} => Some(include_str!(\"../data/day{day:02}.txt\")),"),
+    )?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Flips the day's row of the README progress table to in progress.
+fn mark_in_progress(text: &str, day: u8) -> Result<String, String> {
+    let row = format!("|Day {day}|");
+    let mut found = false;
+    let lines: Vec<_> = text
+        .lines()
+        .map(|line| {
+            if line.starts_with(&row) {
+                found = true;
+                format!("{row}{}|{}|", Status::InProgress, Status::InProgress)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    if found {
+        Ok(lines.join("\n") + "\n")
+    } else {
+        Err(format!(
+            "Could not find a row for day {day} that hasn't been started"
+        ))
+    }
+}
+
+/// Inserts `line` among `lines[range]`, which are assumed to be sorted, keeping them so. Lines
+/// are compared by the digits in them, so that days sort numerically whatever their padding.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    range: std::ops::Range<usize>,
+    line: String,
+) -> Result<(), String> {
+    let key = |line: &str| -> Option<u32> {
+        let digits: String = line
+            .chars()
+            .skip_while(|c| !c.is_ascii_digit())
+            .take_while(char::is_ascii_digit)
+            .collect();
+        digits.parse().ok()
+    };
+    let new_key = key(&line);
+    if lines[range.clone()]
+        .iter()
+        .any(|existing| key(existing) == new_key)
+    {
+        return Err(format!("{} is already there", line.trim()));
+    }
+    let index = range.start
+        + lines[range.clone()]
+            .iter()
+            .take_while(|existing| key(existing) < new_key)
+            .count();
+    lines.insert(index, line);
+    Ok(())
+}
+
+/// Lays out a list of imports the way rustfmt does, filling lines up to 100 columns.
+fn format_use_list(start: &str, imports: &[String]) -> String {
+    const MAX_WIDTH: usize = 100;
+    let single = format!("{start}{}}};", imports.join(", "));
+    if single.len() <= MAX_WIDTH {
+        return single;
+    }
+    let mut text = format!("{start}\n");
+    let mut line = String::new();
+    for import in imports {
+        let item = format!("{import},");
+        if !line.is_empty() && 4 + line.len() + 1 + item.len() > MAX_WIDTH {
+            text.push_str(&format!("    {line}\n"));
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&item);
+    }
+    text.push_str(&format!("    {line}\n}};"));
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let output = render_template(10);
+        assert!(output.contains("pub struct Day10;"));
+        assert!(output.contains("const DAY: u8 = 10;"));
+        assert!(!output.contains("{{"));
+    }
+
+    #[test]
+    fn test_add_module() {
+        const INPUT: &str = "pub mod day01;\npub mod day02;\npub mod day11;\n";
+        const EXPECTED: &str = "pub mod day01;\npub mod day02;\npub mod day10;\npub mod day11;\n";
+        let output = add_module(INPUT, 10);
+        assert_eq!(output.as_deref(), Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_add_module_already_there() {
+        const INPUT: &str = "pub mod day01;\npub mod day02;\n";
+        let output = add_module(INPUT, 2);
+        assert_eq!(output, Err("pub mod day02; is already there".to_string()));
+    }
+
+    #[test]
+    fn test_register() {
+        const INPUT: &str = "\
+use crate::days::{
+    day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+    day07::Day07, day08::Day08, day09::Day09,
+};
+
+pub const SOLUTIONS: &[Registration] = &[
+    Registration::new::<Day08>(),
+    Registration::new::<Day09>(),
+];
+";
+        const EXPECTED: &str = "\
+use crate::days::{
+    day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+    day07::Day07, day08::Day08, day09::Day09, day10::Day10,
+};
+
+pub const SOLUTIONS: &[Registration] = &[
+    Registration::new::<Day08>(),
+    Registration::new::<Day09>(),
+    Registration::new::<Day10>(),
+];
+";
+        let output = register(INPUT, 10);
+        assert_eq!(output.as_deref(), Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_embed_input() {
+        const INPUT: &str = "\
+#[cfg(feature = \"embed-inputs\")]
+fn embedded(day: u8) -> Option<&'static str> {
+    match day {
+        9 => Some(include_str!(\"../data/day09.txt\")),
+        _ => None,
+    }
+}
+";
+        const EXPECTED: &str = "\
+#[cfg(feature = \"embed-inputs\")]
+fn embedded(day: u8) -> Option<&'static str> {
+    match day {
+        9 => Some(include_str!(\"../data/day09.txt\")),
+        10 => Some(include_str!(\"../data/day10.txt\")),
+        _ => None,
+    }
+}
+";
+        let output = embed_input(INPUT, 10);
+        assert_eq!(output.as_deref(), Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_mark_in_progress() {
+        const INPUT: &str = "|Puzzle|Part 1|Part 2|\n|Day 1|:x:|:x:|\n|Day 10|:x:|:x:|\n";
+        const EXPECTED: &str =
+            "|Puzzle|Part 1|Part 2|\n|Day 1|:x:|:x:|\n|Day 10|:construction:|:construction:|\n";
+        let output = mark_in_progress(INPUT, 10);
+        assert_eq!(output.as_deref(), Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_new_day_in_this_tree() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let output = new_day(root, 5);
+        assert_eq!(
+            output,
+            Err(format!(
+                "{} already exists",
+                root.join("src/days/day05.rs").display()
+            ))
+        );
+    }
+}
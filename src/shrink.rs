@@ -0,0 +1,302 @@
+//! Shrinks an input that a day fails on down to a small one that still fails the same way, by
+//! deleting as much of it as it can (delta debugging).
+//!
+//! Inputs are cut into pieces that can be deleted on their own: lines for most days, the
+//! sections of each map and the seed ranges for day 5, and the nodes for day 8. Pieces that hold
+//! the input together, such as headers and blank lines, are always kept.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::differential::{self, Outcome};
+use crate::registry::Registration;
+use crate::Part;
+
+/// Where reproducers are written to by default.
+pub const REPRODUCER_DIR: &str = "target/shrink";
+
+/// A part of an input that can be deleted without touching the rest.
+#[derive(Debug)]
+struct Piece<'a> {
+    text: &'a str,
+    removable: bool,
+    /// Pieces in the same block are tried together first, like all the sections of one map.
+    block: usize,
+}
+
+/// Shrinks `input` for as long as `fails` still holds for what is left, and returns the
+/// smallest input found. No single piece can be deleted from what is returned.
+pub fn shrink(day: u8, input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let pieces = pieces(day, input);
+    let mut kept = vec![true; pieces.len()];
+    let mut try_without = |kept: &mut Vec<bool>, indices: &[usize]| {
+        indices.iter().for_each(|index| kept[*index] = false);
+        if fails(&render(&pieces, kept)) {
+            true
+        } else {
+            indices.iter().for_each(|index| kept[*index] = true);
+            false
+        }
+    };
+    let blocks = pieces.iter().map(|piece| piece.block).max().unwrap_or(0);
+    for block in 0..=blocks {
+        let indices: Vec<_> = (0..pieces.len())
+            .filter(|index| pieces[*index].removable && pieces[*index].block == block)
+            .collect();
+        if !indices.is_empty() {
+            try_without(&mut kept, &indices);
+        }
+    }
+    let removable = |kept: &[bool]| -> Vec<usize> {
+        (0..pieces.len())
+            .filter(|index| pieces[*index].removable && kept[*index])
+            .collect()
+    };
+    let mut chunk = (removable(&kept).len() / 2).max(1);
+    loop {
+        let indices = removable(&kept);
+        let mut deleted = false;
+        for indices in indices.chunks(chunk) {
+            deleted |= try_without(&mut kept, indices);
+        }
+        if !deleted {
+            if chunk == 1 {
+                break;
+            }
+            chunk = (chunk / 2).max(1);
+        }
+    }
+    render(&pieces, &kept)
+}
+
+/// Shrinks an input that `implementations` disagree on, or panic on, for as long as they still
+/// fail the same way. Returns `None` if they don't fail on `input` at all.
+pub fn shrink_failure(
+    day: u8,
+    implementations: &[(&'static str, Registration)],
+    input: &str,
+) -> Option<String> {
+    let failure = |input: &str| {
+        differential::compare(implementations, input)
+            .map(|(part, outcomes)| signature(part, &outcomes))
+    };
+    let original = failure(input)?;
+    Some(shrink(day, input, |input| {
+        failure(input).as_ref() == Some(&original)
+    }))
+}
+
+/// Writes a reproducer for a day into `dir`, returning where it went.
+pub fn write_reproducer(dir: &Path, day: u8, input: &str) -> Result<PathBuf, String> {
+    let path = dir.join(format!("day{day:02}.txt"));
+    fs::create_dir_all(dir)
+        .and_then(|()| fs::write(&path, input))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))?;
+    Ok(path)
+}
+
+/// What makes two failures the same: the part, and which implementations panicked with what.
+/// Numbers are left out of panic messages, as they often hold indices that change as the input
+/// shrinks.
+fn signature(part: Part, outcomes: &[(&'static str, Outcome)]) -> (Part, Vec<Option<String>>) {
+    let panics = outcomes
+        .iter()
+        .map(|(_, outcome)| match outcome {
+            Outcome::Panic(message) => Some(message.replace(|c: char| c.is_ascii_digit(), "")),
+            _ => None,
+        })
+        .collect();
+    (part, panics)
+}
+
+fn render(pieces: &[Piece], kept: &[bool]) -> String {
+    pieces
+        .iter()
+        .zip(kept)
+        .filter(|(_, kept)| **kept)
+        .map(|(piece, _)| piece.text)
+        .collect()
+}
+
+fn pieces(day: u8, input: &str) -> Vec<Piece<'_>> {
+    match day {
+        5 => almanac_pieces(input),
+        8 => network_pieces(input),
+        _ => input
+            .split_inclusive('\n')
+            .map(|line| Piece {
+                text: line,
+                removable: !line.trim().is_empty(),
+                block: 0,
+            })
+            .collect(),
+    }
+}
+
+/// Every seed range, and every section of every map, with each map as a block of its own.
+fn almanac_pieces(input: &str) -> Vec<Piece<'_>> {
+    let mut pieces = Vec::new();
+    let mut block = 0;
+    for line in input.split_inclusive('\n') {
+        if let Some(numbers) = line.strip_prefix("seeds:") {
+            pieces.push(Piece {
+                text: &line[..line.len() - numbers.len()],
+                removable: false,
+                block,
+            });
+            pieces.extend(seed_ranges(numbers).into_iter().map(|text| Piece {
+                text,
+                removable: !text.trim().is_empty(),
+                block,
+            }));
+            continue;
+        }
+        let header = line.trim_end().ends_with(':');
+        if header {
+            block += 1;
+        }
+        pieces.push(Piece {
+            text: line,
+            removable: !header && !line.trim().is_empty(),
+            block,
+        });
+    }
+    pieces
+}
+
+/// Splits the numbers after `seeds:` into pairs, each keeping the space before it, with
+/// anything after the last pair left on its own.
+fn seed_ranges(numbers: &str) -> Vec<&str> {
+    let mut ranges = Vec::new();
+    let mut rest = numbers;
+    loop {
+        let mut end = 0;
+        for _ in 0..2 {
+            let start = rest[end..]
+                .find(|c: char| !c.is_whitespace())
+                .map_or(rest.len(), |index| end + index);
+            end = rest[start..]
+                .find(char::is_whitespace)
+                .map_or(rest.len(), |index| start + index);
+        }
+        if rest[..end].trim().is_empty() {
+            break;
+        }
+        ranges.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    ranges.push(rest);
+    ranges
+}
+
+/// Every node, keeping the line of directions.
+fn network_pieces(input: &str) -> Vec<Piece<'_>> {
+    let mut directions = true;
+    input
+        .split_inclusive('\n')
+        .map(|line| {
+            let blank = line.trim().is_empty();
+            let removable = !blank && !directions;
+            directions &= blank;
+            Piece {
+                text: line,
+                removable,
+                block: 0,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseError, Solution, SolveError};
+
+    #[test]
+    fn test_shrink_lines() {
+        const INPUT: &str = "1\n2\n3\n4\n5\n6\n7\n8\n";
+        const EXPECTED: &str = "3\n6\n";
+        let output = shrink(1, INPUT, |input| {
+            input.contains("3\n") && input.contains("6\n")
+        });
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_almanac_pieces() {
+        const INPUT: &str = "seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98 2\n52 50 48\n";
+        const EXPECTED: &[(&str, bool, usize)] = &[
+            ("seeds:", false, 0),
+            (" 79 14", true, 0),
+            (" 55 13", true, 0),
+            ("\n", false, 0),
+            ("\n", false, 0),
+            ("seed-to-soil map:\n", false, 1),
+            ("50 98 2\n", true, 1),
+            ("52 50 48\n", true, 1),
+        ];
+        let output: Vec<_> = almanac_pieces(INPUT)
+            .into_iter()
+            .map(|piece| (piece.text, piece.removable, piece.block))
+            .collect();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_network_pieces() {
+        const INPUT: &str = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\n";
+        const EXPECTED: &[bool] = &[false, false, true, true];
+        let output: Vec<_> = network_pieces(INPUT)
+            .into_iter()
+            .map(|piece| piece.removable)
+            .collect();
+        assert_eq!(output, EXPECTED);
+    }
+
+    /// Panics on any network with a ZZZ node, standing in for a day with a bug.
+    struct PanicsOnZzz;
+
+    impl Solution for PanicsOnZzz {
+        const DAY: u8 = 8;
+        const TITLE: &'static str = "Panics on ZZZ";
+        type Parsed<'a> = &'a str;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            Ok(input)
+        }
+
+        fn solve_part_1(network: &Self::Parsed<'_>) -> Result<Self::Part1, SolveError> {
+            assert!(!network.contains("ZZZ ="), "found ZZZ");
+            Ok(0)
+        }
+
+        fn solve_part_2(_network: &Self::Parsed<'_>) -> Result<Self::Part2, SolveError> {
+            Ok(0)
+        }
+    }
+
+    #[test]
+    fn test_shrink_failure_panic() {
+        const INPUT: &str = "\
+LR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+        const EXPECTED: &str = "LR\n\nZZZ = (ZZZ, ZZZ)\n";
+        let implementations = [("solution", Registration::new::<PanicsOnZzz>())];
+        let output = shrink_failure(8, &implementations, INPUT);
+        assert_eq!(output.as_deref(), Some(EXPECTED));
+    }
+
+    #[test]
+    fn test_shrink_failure_passing() {
+        const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n";
+        let implementations = [("solution", *crate::registry::find(9).unwrap())];
+        let output = shrink_failure(9, &implementations, INPUT);
+        assert_eq!(output, None);
+    }
+}
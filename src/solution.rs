@@ -0,0 +1,188 @@
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+use crate::num::Overflow;
+use crate::ParseError;
+
+/// A solution to a single day of the puzzle.
+///
+/// The input is parsed once into [`Solution::Parsed`] which is then shared by both parts.
+pub trait Solution {
+    /// The day of the advent calendar this solution is for.
+    const DAY: u8;
+
+    /// The title of the day's puzzle, as shown on the site.
+    const TITLE: &'static str;
+
+    /// The parsed representation of the puzzle input, which may borrow from the input.
+    type Parsed<'a>;
+
+    /// The answer produced by part 1.
+    type Part1: Answer;
+
+    /// The answer produced by part 2.
+    type Part2: Answer;
+
+    /// Parses the puzzle input, which fails at the first thing in it the puzzle doesn't allow.
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    /// Solves part 1, which fails if the answer overflows or the input has no answer.
+    fn solve_part_1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1, SolveError>;
+
+    /// Solves part 2, which fails if the answer overflows or the input has no answer.
+    fn solve_part_2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2, SolveError>;
+}
+
+/// Why a day couldn't be solved.
+#[derive(Debug, PartialEq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// A part overflowed the type it was solved in, while overflows were being checked or with no
+    /// wider type to fall back to.
+    Overflow(Overflow),
+    /// A part has no answer for an input that parsed.
+    NoAnswer(NoAnswer),
+}
+
+impl SolveError {
+    /// Records the day the error happened on.
+    pub fn on_day(self, day: u8) -> Self {
+        match self {
+            SolveError::Parse(error) => SolveError::Parse(error.on_day(day)),
+            SolveError::Overflow(overflow) => SolveError::Overflow(overflow.on_day(day)),
+            SolveError::NoAnswer(no_answer) => SolveError::NoAnswer(no_answer.on_day(day)),
+        }
+    }
+
+    /// Records the part that failed, which a parse error doesn't have.
+    pub fn in_part(self, part: Part) -> Self {
+        match self {
+            SolveError::Parse(error) => SolveError::Parse(error),
+            SolveError::Overflow(overflow) => SolveError::Overflow(overflow.in_part(part)),
+            SolveError::NoAnswer(no_answer) => SolveError::NoAnswer(no_answer.in_part(part)),
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(error) => write!(f, "{error}"),
+            SolveError::Overflow(overflow) => write!(f, "{overflow}"),
+            SolveError::NoAnswer(no_answer) => write!(f, "{no_answer}"),
+        }
+    }
+}
+
+impl core::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        SolveError::Parse(error)
+    }
+}
+
+impl From<Overflow> for SolveError {
+    fn from(overflow: Overflow) -> Self {
+        SolveError::Overflow(overflow)
+    }
+}
+
+impl From<NoAnswer> for SolveError {
+    fn from(no_answer: NoAnswer) -> Self {
+        SolveError::NoAnswer(no_answer)
+    }
+}
+
+/// An input that parsed, but that a part has no answer for, such as a walk that never ends.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoAnswer {
+    day: Option<u8>,
+    part: Option<Part>,
+    reason: String,
+}
+
+impl NoAnswer {
+    pub fn new(reason: String) -> Self {
+        Self {
+            day: None,
+            part: None,
+            reason,
+        }
+    }
+
+    /// Records the day that has no answer.
+    pub fn on_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    /// Records the part that has no answer.
+    pub fn in_part(self, part: Part) -> Self {
+        Self {
+            part: Some(part),
+            ..self
+        }
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn part(&self) -> Option<Part> {
+        self.part
+    }
+
+    /// Why there is no answer, such as where a walk starts going round in circles.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => write!(f, "day {day}, part {part}: ")?,
+            (Some(day), None) => write!(f, "day {day}: ")?,
+            (None, Some(part)) => write!(f, "part {part}: ")?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl core::error::Error for NoAnswer {}
+
+/// What a part of a day produces, which is shown along with the type it was produced in.
+pub trait Answer: Display {
+    /// The name of the type the answer was produced in, such as `u32`, which can depend on the
+    /// answer when it was solved again in a wider type.
+    fn type_name(&self) -> &'static str;
+}
+
+/// One of the two parts of a day's puzzle.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// The number of the part, 1 or 2.
+    pub const fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
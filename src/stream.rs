@@ -0,0 +1,172 @@
+//! Solving straight from a reader a line at a time, for days where each line can be handled on
+//! its own, so that inputs far larger than memory can be solved. Days can implement
+//! [`Streaming`] without `std`, but need it to be solved from a reader.
+
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
+#[cfg(feature = "std")]
+use std::string::String;
+
+#[cfg(feature = "std")]
+use crate::normalise::{self, Tabs};
+
+use crate::num::Overflow;
+use crate::{ParseError, Solution};
+
+/// A day that can fold its answers together as the lines of its input arrive, without holding
+/// on to the input. Both parts are solved in the same pass, as a reader can't be rewound.
+pub trait Streaming: Solution {
+    /// What a line of the input is parsed into, which may borrow from the line.
+    type Line<'a>;
+
+    /// What is carried from one line to the next, which should stay the same size however long
+    /// the input is.
+    type State: Default;
+
+    /// Parses a single line, which has been trimmed and is never empty.
+    fn parse_line(line: &str) -> Result<Self::Line<'_>, ParseError>;
+
+    /// Folds a line into the state, which only fails if an answer overflows the type it is
+    /// solved in.
+    fn fold_line(state: &mut Self::State, line: Self::Line<'_>) -> Result<(), Overflow>;
+
+    fn finish(state: Self::State) -> (Self::Part1, Self::Part2);
+}
+
+/// Why a streamed input couldn't be solved.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+    Overflow(Overflow),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "Failed to read input: {error}"),
+            StreamError::Parse(error) => write!(f, "{error}"),
+            StreamError::Overflow(overflow) => write!(f, "{overflow}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StreamError {}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+/// Solves both parts of a day from `reader`, reading one line at a time. Blank lines are
+/// skipped, as the in-memory parsers trim the input, and lines are normalised like the whole
+/// input is, except for dedenting as each line is trimmed anyway.
+#[cfg(feature = "std")]
+pub fn solve<S: Streaming>(mut reader: impl BufRead) -> Result<(S::Part1, S::Part2), StreamError> {
+    let mut state = S::State::default();
+    let mut line = String::new();
+    let mut number = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        number += 1;
+        let text = match number {
+            1 => normalise::strip_bom(&line),
+            _ => &line,
+        };
+        let on_line = |error: ParseError| {
+            StreamError::Parse(error.within(&line, text).on_line(number).on_day(S::DAY))
+        };
+        let text = normalise::handle_tabs(text, Tabs::Reject).map_err(on_line)?;
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let parsed = S::parse_line(trimmed)
+            .map_err(|error| error.within(&text, trimmed))
+            .map_err(on_line)?;
+        S::fold_line(&mut state, parsed)
+            .map_err(|overflow| StreamError::Overflow(overflow.on_day(S::DAY)))?;
+    }
+    Ok(S::finish(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+    use crate::days::{day01::Day01, day02::Day02, day04::Day04, day09::Day09};
+    use crate::generate;
+    use crate::num::Widened;
+
+    fn agrees_on_generated_inputs<S: Streaming>()
+    where
+        S::Part1: Debug + PartialEq,
+        S::Part2: Debug + PartialEq,
+    {
+        for seed in 0..10 {
+            let input = generate::generate(S::DAY, seed, 50).unwrap();
+            let parsed = S::parse(&input).unwrap();
+            let expected = (
+                S::solve_part_1(&parsed).unwrap(),
+                S::solve_part_2(&parsed).unwrap(),
+            );
+            let output = solve::<S>(input.as_bytes()).unwrap();
+            assert_eq!(output, expected, "day {} seed {seed}", S::DAY);
+        }
+    }
+
+    #[test]
+    fn test_solve_agrees_with_parsed() {
+        agrees_on_generated_inputs::<Day01>();
+        agrees_on_generated_inputs::<Day02>();
+        agrees_on_generated_inputs::<Day04>();
+        agrees_on_generated_inputs::<Day09>();
+    }
+
+    #[test]
+    fn test_solve() {
+        const INPUT: &str = "0 3 6 9 12 15\n\n1 3 6 10 15 21\r\n  10 13 16 21 30 45";
+        const EXPECTED: (Widened<i32>, Widened<i32>) = (Widened::Narrow(114), Widened::Narrow(2));
+        let output = solve::<Day09>(INPUT.as_bytes()).unwrap();
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_solve_normalises_lines() {
+        const INPUT: &str = "\u{feff}0 3 6 9 12 15\r\n1 3 6 10 15 21\r\n";
+        const EXPECTED: (Widened<i32>, Widened<i32>) = (Widened::Narrow(46), Widened::Narrow(-3));
+        let output = solve::<Day09>(INPUT.as_bytes()).unwrap();
+        assert_eq!(output, EXPECTED);
+        let output = solve::<Day09>("0 3 6\n1\t3 6\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            output.to_string(),
+            "day 9, line 2, column 2: expected spaces, not a tab, found \"\\t3 6\""
+        );
+    }
+
+    #[test]
+    fn test_solve_parse_error() {
+        const INPUT: &str = "0 3 6 9 12 15\n\n  1 3 x 10 15 21\n";
+        const EXPECTED: &str = "day 9, line 3, column 7: expected a number, found \"x\"";
+        let output = solve::<Day09>(INPUT.as_bytes()).unwrap_err();
+        assert_eq!(output.to_string(), EXPECTED);
+    }
+
+    #[test]
+    fn test_solve_invalid_utf8() {
+        const INPUT: &[u8] = b"0 3 6\n\xff\n";
+        let output = solve::<Day09>(INPUT).unwrap_err();
+        assert!(matches!(output, StreamError::Io(_)));
+    }
+}
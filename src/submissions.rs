@@ -0,0 +1,312 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::json::Value;
+use crate::{ParseError, Part};
+
+/// Path of the record of every answer submitted, relative to the root of the repository.
+pub const HISTORY_PATH: &str = "data/submissions.json";
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+}
+
+impl Verdict {
+    const ALL: [Verdict; 5] = [
+        Verdict::Right,
+        Verdict::Wrong,
+        Verdict::TooHigh,
+        Verdict::TooLow,
+        Verdict::RateLimited,
+    ];
+
+    /// Reads the verdict from the page returned after submitting an answer.
+    pub fn from_response(body: &str) -> Option<Self> {
+        if body.contains("That's the right answer") {
+            Some(Verdict::Right)
+        } else if body.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited)
+        } else if body.contains("That's not the right answer") {
+            if body.contains("your answer is too high") {
+                Some(Verdict::TooHigh)
+            } else if body.contains("your answer is too low") {
+                Some(Verdict::TooLow)
+            } else {
+                Some(Verdict::Wrong)
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Whether the answer is known to be wrong, as opposed to not having been checked.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::RateLimited => "rate limited",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// An answer that was submitted, and what came of it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// When the answer was submitted, in seconds since the Unix epoch.
+    pub time: u64,
+}
+
+/// Why an answer shouldn't be submitted at all.
+#[derive(Debug, PartialEq)]
+pub enum Refusal {
+    AlreadySubmitted(Verdict),
+    AlreadySolved(String),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySubmitted(verdict) => {
+                write!(f, "it was already submitted and was {verdict}")
+            }
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "the part was already solved with {answer}")
+            }
+        }
+    }
+}
+
+/// A sign that an answer is wrong without having to submit it.
+#[derive(Debug, PartialEq)]
+pub struct Contradiction {
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+impl fmt::Display for Contradiction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} was already {}", self.answer, self.verdict)
+    }
+}
+
+/// Every answer submitted so far.
+#[derive(Debug, Default, PartialEq)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the history, starting an empty one if nothing has been submitted yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|error| format!("{}: {error}", path.display())),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("Failed to read {}: {error}", path.display())),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let invalid = || ParseError::new(text, text.trim_start(), "a history of submissions");
+        let attempts = match Value::parse(text)?.get("attempts") {
+            Some(Value::Array(attempts)) => attempts
+                .iter()
+                .map(attempt_from_json)
+                .collect::<Option<_>>()
+                .ok_or_else(invalid)?,
+            _ => return Err(invalid()),
+        };
+        Ok(Self { attempts })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, format!("{self}\n"))
+            .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+    }
+
+    pub fn attempts(&self, day: u8, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.part == part)
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Checks whether `answer` is worth submitting, returning any recorded bound it falls
+    /// outside of.
+    pub fn check(
+        &self,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Option<Contradiction>, Refusal> {
+        for attempt in self.attempts(day, part) {
+            if attempt.verdict == Verdict::Right {
+                return Err(Refusal::AlreadySolved(attempt.answer.clone()));
+            }
+            if attempt.answer == answer && attempt.verdict.is_wrong() {
+                return Err(Refusal::AlreadySubmitted(attempt.verdict));
+            }
+        }
+        let Ok(answer) = answer.parse::<i128>() else {
+            return Ok(None);
+        };
+        let contradiction = self.attempts(day, part).find(|attempt| {
+            match (attempt.verdict, attempt.answer.parse::<i128>()) {
+                (Verdict::TooHigh, Ok(bound)) => answer >= bound,
+                (Verdict::TooLow, Ok(bound)) => answer <= bound,
+                _ => false,
+            }
+        });
+        Ok(contradiction.map(|attempt| Contradiction {
+            verdict: attempt.verdict,
+            answer: attempt.answer.clone(),
+        }))
+    }
+}
+
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let attempts = self.attempts.iter().map(attempt_to_json).collect();
+        write!(f, "{}", Value::from([("attempts", Value::Array(attempts))]))
+    }
+}
+
+fn attempt_to_json(attempt: &Attempt) -> Value {
+    Value::from([
+        ("day", Value::from(attempt.day as f64)),
+        ("part", Value::from(attempt.part.to_string())),
+        ("answer", Value::from(attempt.answer.as_str())),
+        ("verdict", Value::from(attempt.verdict.to_string())),
+        ("time", Value::from(attempt.time as f64)),
+    ])
+}
+
+fn attempt_from_json(value: &Value) -> Option<Attempt> {
+    let part = match value.get("part")?.as_str()? {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return None,
+    };
+    let verdict = value.get("verdict")?.as_str()?;
+    Some(Attempt {
+        day: value.get("day")?.as_f64()? as u8,
+        part,
+        answer: value.get("answer")?.as_str()?.to_string(),
+        verdict: Verdict::ALL
+            .into_iter()
+            .find(|known| known.to_string() == verdict)?,
+        time: value.get("time")?.as_f64()? as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            day: 5,
+            part: Part::Two,
+            answer: answer.to_string(),
+            verdict,
+            time: 1701792000,
+        }
+    }
+
+    fn history(attempts: &[(&str, Verdict)]) -> History {
+        History {
+            attempts: attempts
+                .iter()
+                .map(|(answer, verdict)| attempt(answer, *verdict))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_verdict_from_response() {
+        const INPUTS: [&str; 6] = [
+            "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+            "<article><p>That's not the right answer. If you're stuck, ...</p></article>",
+            "<article><p>That's not the right answer; your answer is too high.</p></article>",
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+            "<article><p>You gave an answer too recently. You have 38s left to wait.</p></article>",
+            "<article><p>You don't seem to be solving the right level.</p></article>",
+        ];
+        const EXPECTED: [Option<Verdict>; 6] = [
+            Some(Verdict::Right),
+            Some(Verdict::Wrong),
+            Some(Verdict::TooHigh),
+            Some(Verdict::TooLow),
+            Some(Verdict::RateLimited),
+            None,
+        ];
+        let output = INPUTS.map(Verdict::from_response);
+        assert_eq!(output, EXPECTED);
+    }
+
+    #[test]
+    fn test_check_already_wrong() {
+        let input = history(&[("100", Verdict::TooHigh)]);
+        let output = input.check(5, Part::Two, "100");
+        assert_eq!(output, Err(Refusal::AlreadySubmitted(Verdict::TooHigh)));
+    }
+
+    #[test]
+    fn test_check_already_solved() {
+        let input = history(&[("100", Verdict::TooHigh), ("42", Verdict::Right)]);
+        let output = input.check(5, Part::Two, "43");
+        assert_eq!(output, Err(Refusal::AlreadySolved("42".to_string())));
+    }
+
+    #[test]
+    fn test_check_rate_limited_can_be_resubmitted() {
+        let input = history(&[("100", Verdict::RateLimited)]);
+        let output = input.check(5, Part::Two, "100");
+        assert_eq!(output, Ok(None));
+    }
+
+    #[test]
+    fn test_check_contradicts_bound() {
+        let input = history(&[("100", Verdict::TooHigh), ("20", Verdict::TooLow)]);
+        let expected = Contradiction {
+            verdict: Verdict::TooLow,
+            answer: "20".to_string(),
+        };
+        assert_eq!(input.check(5, Part::Two, "50"), Ok(None));
+        assert_eq!(input.check(5, Part::Two, "12"), Ok(Some(expected)));
+        assert_eq!(input.check(5, Part::One, "12"), Ok(None));
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let input = history(&[
+            ("100", Verdict::TooHigh),
+            ("a \"quoted\" answer", Verdict::Wrong),
+        ]);
+        let output = History::parse(&input.to_string()).unwrap();
+        assert_eq!(output, input);
+    }
+}
@@ -0,0 +1,245 @@
+//! Explaining how a day reached its answers. Solvers emit events at the decisions worth knowing
+//! about when an answer is wrong, which cost nothing unless they are being collected with
+//! [`collect`], and which can be printed as indented text or as JSON lines. Events are collected
+//! per thread, so without `std` they are never collected and emitting one does nothing.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::cell::{Cell, RefCell};
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+#[cfg(feature = "std")]
+use std::str::FromStr;
+
+#[cfg(feature = "std")]
+use crate::json::Value;
+
+/// Something a solver did, with the scopes it happened within given by its depth.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Event {
+    pub depth: usize,
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+#[cfg(feature = "std")]
+impl Event {
+    pub fn to_json(&self) -> Value {
+        let fields = self
+            .fields
+            .iter()
+            .map(|(key, value)| (key.to_string(), Value::from(value.as_str())))
+            .collect();
+        Value::from([
+            ("depth", Value::from(self.depth as f64)),
+            ("event", Value::from(self.name)),
+            ("fields", Value::Object(fields)),
+        ])
+    }
+}
+
+/// The event indented by its depth, with its fields as `key=value` and any value with spaces
+/// in it quoted.
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:indent$}{}", "", self.name, indent = self.depth * 2)?;
+        for (key, value) in &self.fields {
+            if value.contains(char::is_whitespace) || value.is_empty() {
+                write!(f, " {key}={value:?}")?;
+            } else {
+                write!(f, " {key}={value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How collected events are printed.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[cfg(feature = "std")]
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Invalid trace format {string}")),
+        }
+    }
+}
+
+/// Writes the events from a day, as text under a heading for the day or as a line of JSON for
+/// each event with the day added to it.
+#[cfg(feature = "std")]
+pub fn write(format: Format, day: u8, events: &[Event], out: &mut dyn Write) -> io::Result<()> {
+    if format == Format::Text && !events.is_empty() {
+        writeln!(out, "Day {day:02}")?;
+    }
+    for event in events {
+        match format {
+            Format::Text => writeln!(out, "  {event}")?,
+            Format::Json => {
+                let mut record = event.to_json();
+                if let Value::Object(fields) = &mut record {
+                    fields.insert("day".to_string(), Value::from(day as f64));
+                }
+                writeln!(out, "{record}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    /// The events collected on this thread, or `None` when they aren't being collected.
+    static EVENTS: RefCell<Option<Vec<Event>>> = const { RefCell::new(None) };
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Whether events are being collected on this thread, for work that is only worth doing to
+/// emit an event.
+#[cfg(feature = "std")]
+pub fn enabled() -> bool {
+    EVENTS.with(|events| events.borrow().is_some())
+}
+
+#[cfg(not(feature = "std"))]
+pub fn enabled() -> bool {
+    false
+}
+
+/// Runs `f`, collecting every event emitted on this thread while it runs. Events are collected
+/// per thread so that days solved at the same time don't mix theirs together, which means that
+/// work split between threads is done on this one while collecting.
+#[cfg(feature = "std")]
+pub fn collect<R>(f: impl FnOnce() -> R) -> (R, Vec<Event>) {
+    /// Puts back whatever was being collected before, even if `f` panics.
+    struct Restore(Option<Vec<Event>>, usize);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            EVENTS.with(|events| *events.borrow_mut() = self.0.take());
+            DEPTH.with(|depth| depth.set(self.1));
+        }
+    }
+
+    let previous = EVENTS.with(|events| events.borrow_mut().replace(vec![]));
+    let restore = Restore(previous, DEPTH.with(|depth| depth.replace(0)));
+    let result = f();
+    let events = EVENTS.with(|events| events.borrow_mut().take().unwrap_or_default());
+    drop(restore);
+    (result, events)
+}
+
+/// Emits an event with the given fields, which are only formatted if events are being collected.
+#[cfg(feature = "std")]
+pub fn event(name: &'static str, fields: &[(&'static str, &dyn fmt::Display)]) {
+    EVENTS.with(|events| {
+        if let Some(events) = events.borrow_mut().as_mut() {
+            events.push(Event {
+                depth: DEPTH.with(Cell::get),
+                name,
+                fields: fields
+                    .iter()
+                    .map(|(key, value)| (*key, value.to_string()))
+                    .collect(),
+            });
+        }
+    });
+}
+
+#[cfg(not(feature = "std"))]
+pub fn event(_: &'static str, _: &[(&'static str, &dyn fmt::Display)]) {}
+
+/// Emits an event, and nests the events emitted until the returned scope is dropped under it.
+#[must_use = "events are only nested until the scope is dropped"]
+pub fn scope(name: &'static str, fields: &[(&'static str, &dyn fmt::Display)]) -> Scope {
+    event(name, fields);
+    #[cfg(feature = "std")]
+    DEPTH.with(|depth| depth.set(depth.get() + 1));
+    Scope(())
+}
+
+pub struct Scope(());
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        DEPTH.with(|depth| depth.set(depth.get().saturating_sub(1)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        let ((), events) = collect(|| {
+            let _scope = scope("part", &[("part", &1)]);
+            event("card", &[("card", &"Card 1"), ("matched", &"48 83")]);
+        });
+        let expected = vec![
+            Event {
+                depth: 0,
+                name: "part",
+                fields: vec![("part", "1".to_string())],
+            },
+            Event {
+                depth: 1,
+                name: "card",
+                fields: vec![
+                    ("card", "Card 1".to_string()),
+                    ("matched", "48 83".to_string()),
+                ],
+            },
+        ];
+        assert_eq!(events, expected);
+        assert!(!enabled());
+    }
+
+    #[test]
+    fn test_event_without_collecting() {
+        event("card", &[("card", &1)]);
+        let ((), events) = collect(|| {});
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_write() {
+        let events = [
+            Event {
+                depth: 0,
+                name: "part",
+                fields: vec![("part", "1".to_string())],
+            },
+            Event {
+                depth: 1,
+                name: "card",
+                fields: vec![("card", "1".to_string()), ("matched", "48 83".to_string())],
+            },
+        ];
+        let (mut text, mut json) = (vec![], vec![]);
+        write(Format::Text, 4, &events, &mut text).unwrap();
+        write(Format::Json, 4, &events, &mut json).unwrap();
+        assert_eq!(
+            String::from_utf8(text).unwrap(),
+            "Day 04\n  part part=1\n    card card=1 matched=\"48 83\"\n"
+        );
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            r#"{"day":4,"depth":0,"event":"part","fields":{"part":"1"}}
+{"day":4,"depth":1,"event":"card","fields":{"card":"1","matched":"48 83"}}
+"#
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Noticing when inputs change by polling them, so that a day can be solved again every time
+//! one of its inputs is saved.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// When a file was last modified and how long it was, which between them change whenever the
+/// file is saved.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Stamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+/// Remembers the files it was last polled with, and whether each one existed and when it was
+/// modified.
+#[derive(Debug, Default)]
+pub struct Watcher {
+    stamps: Option<BTreeMap<PathBuf, Option<Stamp>>>,
+}
+
+impl Watcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether any of `paths` has been created, modified or removed since the last poll, or the
+    /// paths themselves are different. The first poll is always a change.
+    pub fn poll(&mut self, paths: impl IntoIterator<Item = PathBuf>) -> bool {
+        let stamps: BTreeMap<_, _> = paths
+            .into_iter()
+            .map(|path| {
+                let stamp = fs::metadata(&path).ok().map(|metadata| Stamp {
+                    modified: metadata.modified().ok(),
+                    len: metadata.len(),
+                });
+                (path, stamp)
+            })
+            .collect();
+        let changed = self.stamps.as_ref() != Some(&stamps);
+        self.stamps = Some(stamps);
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_poll() {
+        let path = env::temp_dir().join(format!("aoc-2023-watch-{}.txt", std::process::id()));
+        let other = path.with_extension("md");
+        fs::write(&path, "0 3 6").unwrap();
+        let mut watcher = Watcher::new();
+        let first = watcher.poll([path.clone()]);
+        let unchanged = watcher.poll([path.clone()]);
+        fs::write(&path, "0 3 6 9").unwrap();
+        let modified = watcher.poll([path.clone()]);
+        let added = watcher.poll([path.clone(), other.clone()]);
+        fs::remove_file(&path).unwrap();
+        let removed = watcher.poll([path.clone(), other.clone()]);
+        let still_removed = watcher.poll([path, other]);
+        assert_eq!(
+            [first, unchanged, modified, added, removed, still_removed],
+            [true, false, true, true, true, false]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use std::path::{Path, PathBuf};
+
+use aoc_2023::answers::{Answers, MANIFEST_PATH};
+use aoc_2023::input::{self, InputSource, DEFAULT_INPUT_DIR};
+use aoc_2023::registry::SOLUTIONS;
+use aoc_2023::Part;
+
+#[test]
+fn test_known_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let answers = Answers::load(&root.join(MANIFEST_PATH)).unwrap();
+    let dir = root.join(DEFAULT_INPUT_DIR);
+    let mut failures = vec![];
+    for registration in SOLUTIONS {
+        let day = registration.day();
+        let named = input::named_inputs(&dir, day).unwrap();
+        let inputs = [(None, input::path_in(&dir, day))]
+            .into_iter()
+            .chain(named.into_iter().map(|(name, path)| (Some(name), path)));
+        for (name, path) in inputs {
+            // a day scaffolded by `aoc new` has an empty input until it is fetched.
+            if path.metadata().is_ok_and(|metadata| metadata.len() == 0) {
+                continue;
+            }
+            let label = path
+                .strip_prefix(&dir)
+                .unwrap_or(&path)
+                .display()
+                .to_string();
+            let results = input::load(day, &InputSource::Path(PathBuf::from(&path)))
+                .map_err(|error| error.to_string())
+                .and_then(|input| {
+                    registration
+                        .solve(&input, &Part::ALL)
+                        .map_err(|error| error.to_string())
+                });
+            let solved = match results {
+                Ok(solved) => solved,
+                Err(error) => {
+                    failures.push(format!("Day {day:02} {label}: {error}"));
+                    continue;
+                }
+            };
+            for answer in solved.answers {
+                let (part, answer) = (answer.part, answer.value);
+                let expected = match &name {
+                    Some(name) => answers.get_named(day, name, part),
+                    None => answers.get(day, part),
+                };
+                match expected {
+                    Some(expected) if expected != answer => failures.push(format!(
+                        "Day {day:02} {label} part {part}: expected {expected}, got {answer}"
+                    )),
+                    Some(_) => {}
+                    None => failures.push(format!(
+                        "Day {day:02} {label} part {part}: no known answer in {MANIFEST_PATH}, got {answer}"
+                    )),
+                }
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "known answers diverged:\n{}",
+        failures.join("\n")
+    );
+}
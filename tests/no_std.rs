@@ -0,0 +1,21 @@
+use std::path::Path;
+use std::process::Command;
+
+/// Builds the library without the `std` feature, which nothing else does, so that a day that
+/// reaches for `std` is caught by the tests. It gets its own target directory, as building with
+/// different features in the same one would rebuild everything the next time the tests run.
+#[test]
+fn test_builds_without_std() {
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("no-std");
+    let output = Command::new(env!("CARGO"))
+        .args(["build", "--lib", "--no-default-features", "--target-dir"])
+        .arg(target_dir)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}